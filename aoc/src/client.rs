@@ -0,0 +1,520 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use runner::{Hint, InputSource};
+
+pub const YEAR: u16 = 2023;
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/leonardfactory/advent-of-code-2023 runner";
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session token to authenticate with
+    NoSession,
+    /// The server answered with an error status
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::NoSession => {
+                write!(
+                    f,
+                    "no session token, set AOC_SESSION or `session` in aoc.toml"
+                )
+            }
+            ClientError::Status(400, _) => {
+                write!(
+                    f,
+                    "server refused the request (400), is the session token valid?"
+                )
+            }
+            ClientError::Status(404, _) => {
+                write!(f, "not found (404), is the puzzle unlocked yet?")
+            }
+            ClientError::Status(code, body) => {
+                write!(f, "server error {}: {}", code, body.trim())
+            }
+            ClientError::Transport(err) => write!(f, "request failed: {}", err),
+            ClientError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(code, response) => {
+                ClientError::Status(code, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(err) => ClientError::Transport(err.to_string()),
+        }
+    }
+}
+
+/// Keeps requests at least `interval` apart, even across separate runs, by
+/// remembering the time of the last one in a file.
+pub struct Throttle {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(path: impl AsRef<Path>, interval: Duration) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            interval,
+        }
+    }
+
+    /// Sleeps until the next request is allowed, then records it as made.
+    pub fn wait(&self) -> io::Result<()> {
+        let last = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(|nanos| UNIX_EPOCH + Duration::from_nanos(nanos));
+
+        if let Some(elapsed) = last.and_then(|last| SystemTime::now().duration_since(last).ok()) {
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        fs::write(&self.path, now.as_nanos().to_string())
+    }
+}
+
+/// How the website judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong(Option<Hint>),
+    /// Submitted too soon after the previous answer
+    RateLimited(Duration),
+    /// The part was already solved, so nothing was checked
+    AlreadySolved,
+    /// Anything else, with the text of the page
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Classifies the HTML page returned after posting an answer.
+    pub fn from_page(page: &str) -> Self {
+        let text = page
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited(parse_wait(text).unwrap_or_default())
+        } else if text.contains("your answer is too high") {
+            Outcome::Wrong(Some(Hint::TooHigh))
+        } else if text.contains("your answer is too low") {
+            Outcome::Wrong(Some(Hint::TooLow))
+        } else if text.contains("That's not the right answer") {
+            Outcome::Wrong(None)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown(strip_tags(text))
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong(Some(Hint::TooHigh)) => write!(f, "wrong, too high"),
+            Outcome::Wrong(Some(Hint::TooLow)) => write!(f, "wrong, too low"),
+            Outcome::Wrong(None) => write!(f, "wrong"),
+            Outcome::RateLimited(wait) => {
+                write!(
+                    f,
+                    "rate limited, wait {}s before trying again",
+                    wait.as_secs()
+                )
+            }
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown(text) => write!(f, "unexpected response: {}", text),
+        }
+    }
+}
+
+/// Reads waits such as "You have 1m 12s left to wait" or "41s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let secs = before
+        .split_whitespace()
+        .rev()
+        .map_while(|token| {
+            let unit = match token.chars().last()? {
+                's' => 1,
+                'm' => 60,
+                'h' => 3600,
+                _ => return None,
+            };
+            let value = token[..token.len() - 1].parse::<u64>().ok()?;
+            Some(value * unit)
+        })
+        .sum();
+    Some(Duration::from_secs(secs))
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Talks to the Advent of Code website, or to anything serving the same paths
+/// under `base_url`.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, throttle: Throttle) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().trim_start_matches("session=").to_string(),
+            throttle,
+        }
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, YEAR, day, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, ClientError> {
+        self.throttle.wait()?;
+        let response = self
+            .agent
+            .get(&self.url(day, "/input"))
+            .set("Cookie", &self.cookie())
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Outcome, ClientError> {
+        self.throttle.wait()?;
+        let response = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer.trim())])?;
+        Ok(Outcome::from_page(&response.into_string()?))
+    }
+}
+
+/// Downloaded files, laid out like the inputs directory so it can be used as
+/// one (`<cache>/day-xx/input.txt`).
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        match InputSource::for_day(&self.dir, day) {
+            InputSource::File(path) => path,
+            InputSource::Stdin => unreachable!(),
+        }
+    }
+
+    /// Returns the cached input of a day and whether it was just downloaded.
+    /// `client` is only called when a download is needed.
+    pub fn input(
+        &self,
+        day: u8,
+        client: impl FnOnce() -> Result<Client, ClientError>,
+    ) -> Result<(PathBuf, bool), ClientError> {
+        let path = self.input_path(day);
+        if path.exists() {
+            return Ok((path, false));
+        }
+
+        let input = client()?.fetch_input(day)?;
+        fs::create_dir_all(path.parent().unwrap())?;
+        // Written aside and renamed, so an interrupted download never looks cached
+        let partial = path.with_extension("part");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)?;
+        Ok((path, true))
+    }
+}
+
+#[cfg(test)]
+pub mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// A request received by the [`Server`].
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    /// Minimal HTTP server answering every request with the same body, and
+    /// recording what it received.
+    pub struct Server {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl Server {
+        pub fn start(status: u16, body: &'static str) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+
+            let received = requests.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let mut parts = line.split_whitespace();
+                    let method = parts.next().unwrap_or_default().to_string();
+                    let path = parts.next().unwrap_or_default().to_string();
+
+                    let mut cookie = None;
+                    let mut length = 0;
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        let header = header.trim_end();
+                        if header.is_empty() {
+                            break;
+                        }
+                        let (name, value) = header.split_once(": ").unwrap();
+                        match name.to_lowercase().as_str() {
+                            "cookie" => cookie = Some(value.to_string()),
+                            "content-length" => length = value.parse().unwrap(),
+                            _ => {}
+                        }
+                    }
+                    let mut request_body = vec![0; length];
+                    reader.read_exact(&mut request_body).unwrap();
+
+                    received.lock().unwrap().push(Request {
+                        method,
+                        path,
+                        cookie,
+                        body: String::from_utf8(request_body).unwrap(),
+                    });
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+
+            Self { url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::{stub::Server, *};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let server = Server::start(200, "1 2 3\n");
+        let dir = temp_dir("fetch");
+        let client = Client::new(
+            &server.url,
+            "session=abc",
+            Throttle::new(dir.join("last-request"), Duration::ZERO),
+        );
+        let cache = Cache::new(&dir);
+
+        let (path, fetched) = cache.input(5, || Ok(client)).unwrap();
+        assert!(fetched);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        let (_, fetched) = cache.input(5, || panic!("should not fetch")).unwrap();
+        assert!(!fetched);
+
+        let requests = server.requests();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2023/day/5/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let server = Server::start(400, "Puzzle inputs differ by user.");
+        let dir = temp_dir("fetch-error");
+        let client = Client::new(
+            &server.url,
+            "expired",
+            Throttle::new(dir.join("last-request"), Duration::ZERO),
+        );
+        let cache = Cache::new(&dir);
+
+        let err = cache.input(5, || Ok(client)).unwrap_err();
+        let cached = cache.input_path(5).exists();
+        let _ = fs::remove_dir_all(&dir);
+        assert!(matches!(err, ClientError::Status(400, _)));
+        assert!(!cached);
+    }
+
+    #[test]
+    fn test_outcome() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+
+        assert_eq!(
+            Outcome::from_page(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::from_page(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Outcome::Wrong(Some(Hint::TooHigh))
+        );
+        assert_eq!(
+            Outcome::from_page(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::Wrong(Some(Hint::TooLow))
+        );
+        assert_eq!(
+            Outcome::from_page(&page("That's not the right answer. If you're stuck, ...")),
+            Outcome::Wrong(None)
+        );
+        assert_eq!(
+            Outcome::from_page(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 12s left to wait."
+            )),
+            Outcome::RateLimited(Duration::from_secs(72))
+        );
+        assert_eq!(
+            Outcome::from_page(&page(
+                "You gave an answer too recently. You have 41s left to wait."
+            )),
+            Outcome::RateLimited(Duration::from_secs(41))
+        );
+        assert_eq!(
+            Outcome::from_page(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(
+            Outcome::from_page(&page("Something <b>else</b>")),
+            Outcome::Unknown("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = Server::start(200, "<article><p>That's the right answer!</p></article>");
+        let dir = temp_dir("submit");
+        let client = Client::new(
+            &server.url,
+            "abc",
+            Throttle::new(dir.join("last-request"), Duration::ZERO),
+        );
+
+        let outcome = client.submit(10, 2, "435\n").unwrap();
+        let requests = server.requests();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/10/answer");
+        assert_eq!(requests[0].body, "level=2&answer=435");
+    }
+
+    #[test]
+    fn test_throttle() {
+        let dir = temp_dir("throttle");
+        let throttle = Throttle::new(dir.join("last-request"), Duration::from_millis(200));
+
+        let start = Instant::now();
+        throttle.wait().unwrap();
+        throttle.wait().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::client::{Cache, Client, ClientError, Throttle, BASE_URL};
+
+/// Local settings from `aoc.toml`, which is not committed as it holds the
+/// session token. Environment variables take precedence over the file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser (`AOC_SESSION`)
+    pub session: Option<String>,
+    /// Where the website lives, for testing against a stub (`AOC_BASE_URL`)
+    pub base_url: Option<String>,
+    /// Where downloads are kept (`AOC_CACHE`)
+    pub cache: Option<PathBuf>,
+    /// Minimum number of seconds between two requests
+    pub min_interval: Option<u64>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|err| {
+                let msg = format!("{}: {}", path.display(), err);
+                io::Error::new(io::ErrorKind::InvalidData, msg)
+            })?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(err) => return Err(err),
+        };
+        // Relative to the config file rather than to wherever we run from
+        if let (Some(cache), Some(dir)) = (&config.cache, path.parent()) {
+            config.cache = Some(dir.join(cache));
+        }
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        if let Some(cache) = env::var_os("AOC_CACHE") {
+            config.cache = Some(cache.into());
+        }
+        Ok(config)
+    }
+
+    pub fn cache(&self, default: PathBuf) -> Cache {
+        Cache::new(self.cache.clone().unwrap_or(default))
+    }
+
+    pub fn client(&self, cache: &Cache) -> Result<Client, ClientError> {
+        let Some(session) = &self.session else {
+            return Err(ClientError::NoSession);
+        };
+
+        let interval = Duration::from_secs(self.min_interval.unwrap_or(5));
+        Ok(Client::new(
+            self.base_url.as_deref().unwrap_or(BASE_URL),
+            session,
+            Throttle::new(cache.dir().join("last-request"), interval),
+        ))
+    }
+}
@@ -0,0 +1,27 @@
+use runner::Registry;
+
+/// Every solved day, in calendar order. Day 17 is still a work in progress.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<day_01::Day01>()
+        .register::<day_02::Day02>()
+        .register::<day_03::Day03>()
+        .register::<day_04::Day04>()
+        .register::<day_05::Day05>()
+        .register::<day_06::Day06>()
+        .register::<day_07::Day07>()
+        .register::<day_08::Day08>()
+        .register::<day_09::Day09>()
+        .register::<day_10::Day10>()
+        .register::<day_11::Day11>()
+        .register::<day_12::Day12>()
+        .register::<day_13::Day13>()
+        .register::<day_14::Day14>()
+        .register::<day_15::Day15>()
+        .register::<day_16::Day16>()
+        .register::<day_18::Day18>()
+        .register::<day_19::Day19>()
+        .register::<day_20::Day20>();
+    registry
+}
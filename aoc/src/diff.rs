@@ -0,0 +1,85 @@
+use runner::{compare, shrink, Day, Disagreement};
+
+use crate::{generate, Inputs};
+
+/// Where `diff` gets the inputs it compares the implementations on.
+pub enum Source {
+    /// The day's real input, or the one given with `--input`
+    Real,
+    /// Generated inputs of this size, one per seed
+    Generated { size: usize, seeds: Vec<u64> },
+}
+
+/// Runs every implementation of the given parts on each input, stopping at
+/// the first one they disagree on, which is shrunk and printed.
+pub fn diff(day: &Day, parts: &[u8], source: Source, inputs: &Inputs) -> bool {
+    let parts = parts
+        .iter()
+        .copied()
+        .filter(|&part| !day.alternatives(part).is_empty())
+        .collect::<Vec<_>>();
+    if parts.is_empty() {
+        eprintln!("Day {} has no other implementation to compare", day.day);
+        return false;
+    }
+
+    let (checked, disagreement) = match source {
+        Source::Real => {
+            let Some(input) = inputs.read(day) else {
+                return false;
+            };
+            if let Err(err) = day.parse(&input) {
+                eprintln!("Day {}: invalid input at {}", day.day, err);
+                return false;
+            }
+            (1, first_disagreement(day, &parts, &input))
+        }
+        Source::Generated { size, seeds } => {
+            let mut checked = 0;
+            let mut found = None;
+            for seed in seeds {
+                let Some(input) = generate(day, size, seed) else {
+                    return false;
+                };
+                checked += 1;
+                found = first_disagreement(day, &parts, &input);
+                if let Some(disagreement) = &found {
+                    println!(
+                        "Day {:02}: part {} disagrees on seed {}",
+                        day.day, disagreement.part, seed
+                    );
+                    break;
+                }
+            }
+            (checked, found)
+        }
+    };
+
+    match disagreement {
+        None => {
+            println!(
+                "Day {:02}: every implementation agrees on {} input{}",
+                day.day,
+                checked,
+                if checked == 1 { "" } else { "s" }
+            );
+            true
+        }
+        Some(disagreement) => {
+            let lines = disagreement.input.lines().count();
+            let shrunk = shrink(day, disagreement);
+            println!(
+                "Day {:02}: part {} disagrees on this input, shrunk from {} lines:",
+                day.day, shrunk.part, lines
+            );
+            println!("{}", shrunk.input.trim_end());
+            println!();
+            print!("{}", shrunk);
+            false
+        }
+    }
+}
+
+fn first_disagreement(day: &Day, parts: &[u8], input: &str) -> Option<Disagreement> {
+    parts.iter().find_map(|&part| compare(day, part, input))
+}
@@ -0,0 +1,900 @@
+mod client;
+mod config;
+mod days;
+mod diff;
+mod multi;
+mod parallel;
+mod remote;
+mod scaffold;
+mod table;
+mod watch;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{self, ExitCode},
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use clap::{Parser, Subcommand};
+use config::Config;
+use parallel::PartReport;
+use runner::{
+    regression, Answer, Answers, Bench, Budget, Day, Examples, Generator, History, InputSource,
+    ParsedInput, PartError, Phase, Record, Registry, Runner, Stats, Timing, Verdict,
+};
+use table::{Align, Table};
+use toolkit::log::{self, Filter};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Directory holding the `day-xx/input.txt` files
+    #[arg(long, global = true, env = "AOC_INPUTS", default_value_os_t = workspace_path(""))]
+    inputs: PathBuf,
+
+    /// Read the input from this file instead, or from stdin with `-`. Only
+    /// valid when selecting a single day
+    #[arg(long, global = true)]
+    input: Option<String>,
+
+    /// Local settings, such as the session token
+    #[arg(long, global = true, env = "AOC_CONFIG", default_value_os_t = workspace_path("aoc.toml"))]
+    config: PathBuf,
+
+    /// Log filter, such as `day_12=trace,info`
+    #[arg(long, global = true, env = "AOC_LOG", value_parser = parse_log)]
+    log: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day (or `all` of them) against its puzzle input
+    Run {
+        /// Day number, or `all`
+        days: DaySelector,
+
+        /// Run only this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Write the spans as a Chrome trace-event JSON file. Only valid when
+        /// selecting a single day
+        #[arg(long)]
+        trace: Option<PathBuf>,
+
+        /// Write the spans as collapsed stacks, for flamegraph tools. Only
+        /// valid when selecting a single day
+        #[arg(long)]
+        collapsed: Option<PathBuf>,
+
+        /// Run the days this many at a time, each in its own process, and
+        /// print a summary table
+        #[arg(long, short, conflicts_with_all = ["trace", "collapsed"])]
+        jobs: Option<usize>,
+
+        /// With `--jobs`, print what each day wrote, not only for failed days
+        #[arg(long, requires = "jobs")]
+        show_output: bool,
+
+        /// Run the day against every `*.txt` input in this directory, such
+        /// as `inputs/day-18`, checking each against the `<name>.answers.toml`
+        /// next to it. Only valid when selecting a single day
+        #[arg(long, conflicts_with_all = ["input", "jobs", "trace", "collapsed"])]
+        input_dir: Option<PathBuf>,
+
+        /// Print the answers in a form `--jobs` reads back
+        #[arg(long, hide = true)]
+        report: bool,
+
+        /// Give up on a part after this long, e.g. `30s`, and go on with the
+        /// next one
+        #[arg(long, value_parser = parse_duration)]
+        timeout: Option<Duration>,
+    },
+    /// Benchmark parsing and each part of a day (or `all` of them)
+    Bench {
+        /// Day number, or `all`
+        days: DaySelector,
+
+        /// Benchmark only this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Untimed runs before sampling
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Sample exactly this many runs, instead of using the time budget
+        #[arg(long)]
+        runs: Option<usize>,
+
+        /// Keep sampling for this long, e.g. `500ms` or `2s`
+        #[arg(long, default_value = "1s", value_parser = parse_duration)]
+        budget: Duration,
+
+        /// Tag the saved results with this name, to compare against later
+        #[arg(long, value_parser = parse_label)]
+        save: Option<String>,
+
+        /// Compare medians against the latest results saved with this name
+        /// (or git revision), failing if any got slower than `--threshold`
+        #[arg(long)]
+        compare: Option<String>,
+
+        /// Slowdown allowed by `--compare`, in percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// History file the results are appended to
+        #[arg(long, default_value_os_t = workspace_path("bench-history.csv"))]
+        history: PathBuf,
+
+        /// Do not append the results to the history file
+        #[arg(long)]
+        no_history: bool,
+
+        /// Benchmark on a generated input of this size instead of the real
+        /// one. The results are not added to the history
+        #[arg(long, conflicts_with_all = ["save", "compare"])]
+        size: Option<usize>,
+
+        /// Seed of the generated input
+        #[arg(long, default_value_t = 0, requires = "size")]
+        seed: u64,
+    },
+    /// Write a random input for a day, to see how it scales
+    Gen {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// How big the input is, in whatever unit the day picks: lines,
+        /// records, or the range of its numbers
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+
+        /// Seed of the input, random by default
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Where to write the input, by default
+        /// `day-xx/generated/<size>-<seed>.txt` in the inputs directory
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Run the other implementations of a day's parts against the registered
+    /// ones, printing the smallest input they disagree on
+    Diff {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Compare only this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Compare on generated inputs of this size instead of the real one
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// How many generated inputs to try
+        #[arg(long, default_value_t = 100, requires = "size")]
+        count: u64,
+
+        /// Seed of the first generated input, the next ones counting up
+        #[arg(long, default_value_t = 0, requires = "size")]
+        seed: u64,
+    },
+    /// Create a day from the template and register it, copying its input if
+    /// it is already cached
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Download a day's input into the cache, unless it is already there, and
+    /// copy it to the inputs directory
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Solve a part and submit the answer, recording the outcome in the day's
+    /// answers file
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Extract the examples and their answers from a saved puzzle page into
+    /// the day's folder
+    Examples {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The saved puzzle page, or `-` to read it from stdin
+        page: String,
+    },
+    /// Rerun a day's examples, then the day on its input, whenever its
+    /// sources, examples or input change
+    Watch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Check every day (or just one) against its known answers
+    Verify {
+        /// Day number, or `all`
+        #[arg(default_value = "all")]
+        days: DaySelector,
+
+        /// Give up on a part after this long, e.g. `30s`, and go on with the
+        /// next one
+        #[arg(long, value_parser = parse_duration)]
+        timeout: Option<Duration>,
+    },
+}
+
+/// Checks a log filter, keeping it as text to pass it on to child processes.
+fn parse_log(s: &str) -> Result<String, String> {
+    s.parse::<Filter>().map(|_| s.to_string())
+}
+
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (value, unit) = s
+        .find(|c: char| !c.is_ascii_digit())
+        .map(|i| s.split_at(i))
+        .unwrap_or((s, "s"));
+    let value = value
+        .parse::<u64>()
+        .map_err(|_| format!("invalid duration '{}'", s))?;
+
+    match unit {
+        "ms" => Ok(Duration::from_millis(value)),
+        "s" => Ok(Duration::from_secs(value)),
+        "m" => Ok(Duration::from_secs(value * 60)),
+        _ => Err(format!(
+            "invalid duration unit '{}', expected ms, s or m",
+            unit
+        )),
+    }
+}
+
+fn parse_label(s: &str) -> Result<String, String> {
+    if s.contains([',', '\n']) {
+        return Err(format!("invalid label '{}', it cannot contain commas", s));
+    }
+    Ok(s.to_string())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelector {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            s => s
+                .parse::<u8>()
+                .map(Self::Day)
+                .map_err(|_| format!("invalid day '{}', expected a number or 'all'", s)),
+        }
+    }
+}
+
+impl DaySelector {
+    fn select<'a>(&self, registry: &'a Registry) -> Result<Vec<&'a Day>, String> {
+        match *self {
+            Self::All => Ok(registry.days().collect()),
+            Self::Day(day) => registry
+                .get(day)
+                .map(|d| vec![d])
+                .ok_or_else(|| format!("Day {} is not registered", day)),
+        }
+    }
+}
+
+fn workspace_path(path: &str) -> PathBuf {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    workspace.join(path)
+}
+
+fn answers_path(day: u8) -> PathBuf {
+    workspace_path(&format!("day-{:02}/answers.toml", day))
+}
+
+fn extract_examples(day: u8, page: &InputSource) -> bool {
+    let page = match page.read() {
+        Ok(page) => page,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+
+    let found = Examples::extract(&page);
+    if found.is_empty() {
+        eprintln!("No examples with an answer found in the page");
+        return false;
+    }
+
+    let dir = workspace_path(&format!("day-{:02}", day));
+    match Examples::write(&dir, &found) {
+        Ok(examples) if examples.examples.is_empty() => {
+            println!("Day {:02}: every example is already listed", day);
+            true
+        }
+        Ok(examples) => {
+            for example in examples.examples {
+                let answers = [(1, &example.part1), (2, &example.part2)]
+                    .into_iter()
+                    .filter_map(|(part, answer)| {
+                        answer.as_ref().map(|a| format!("part {} = {}", part, a))
+                    })
+                    .collect::<Vec<_>>();
+                println!(
+                    "Day {:02}: {} ({})",
+                    day,
+                    dir.join(&example.input).display(),
+                    answers.join(", ")
+                );
+            }
+            true
+        }
+        Err(err) => {
+            eprintln!("Cannot write the examples to {}: {}", dir.display(), err);
+            false
+        }
+    }
+}
+
+/// A day's generated input, or `None` if it has no generator.
+fn generate(day: &Day, size: usize, seed: u64) -> Option<String> {
+    let input = day.generate(size, seed);
+    if input.is_none() {
+        eprintln!("Day {} has no input generator", day.day);
+    }
+    input
+}
+
+fn write_generated(
+    day: &Day,
+    size: usize,
+    seed: u64,
+    output: Option<PathBuf>,
+    inputs: &Inputs,
+) -> bool {
+    let Some(input) = generate(day, size, seed) else {
+        return false;
+    };
+    let path = output.unwrap_or_else(|| {
+        inputs.dir.join(format!(
+            "day-{:02}/generated/{}-{}.txt",
+            day.day, size, seed
+        ))
+    });
+    let written = match path.parent() {
+        Some(dir) => fs::create_dir_all(dir).and_then(|_| fs::write(&path, input)),
+        None => fs::write(&path, input),
+    };
+    match written {
+        Ok(()) => {
+            println!("Day {:02}: wrote {}", day.day, path.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("Cannot write {}: {}", path.display(), err);
+            false
+        }
+    }
+}
+
+/// Short hash of the checked out commit, marked `-dirty` when there are
+/// uncommitted changes.
+fn git_rev() -> String {
+    let git = |args: &[&str]| {
+        process::Command::new("git")
+            .args(args)
+            .current_dir(workspace_path(""))
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+    };
+
+    let Some(rev) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    let rev = String::from_utf8_lossy(&rev.stdout).trim().to_string();
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.stdout.is_empty() => format!("{}-dirty", rev),
+        _ => rev,
+    }
+}
+
+/// Where to read each day's input from, as set on the command line.
+struct Inputs {
+    dir: PathBuf,
+    input: Option<InputSource>,
+}
+
+impl Inputs {
+    fn source(&self, day: u8) -> InputSource {
+        self.input
+            .clone()
+            .unwrap_or_else(|| InputSource::for_day(&self.dir, day))
+    }
+
+    fn read(&self, day: &Day) -> Option<String> {
+        match self.source(day.day).read() {
+            Ok(input) => Some(input),
+            Err(err) => {
+                eprintln!("Day {}: {}", day.day, err);
+                None
+            }
+        }
+    }
+}
+
+/// Where to export the spans of a run, as set on the command line.
+struct Exports {
+    trace: Option<PathBuf>,
+    collapsed: Option<PathBuf>,
+}
+
+impl Exports {
+    fn write(&self, timing: &Timing) -> bool {
+        let export = |path: &Option<PathBuf>, format: fn(&Timing) -> String| {
+            let Some(path) = path else {
+                return true;
+            };
+            match fs::write(path, format(timing)) {
+                Ok(()) => {
+                    println!("Spans written to {}", path.display());
+                    true
+                }
+                Err(err) => {
+                    eprintln!("Cannot write {}: {}", path.display(), err);
+                    false
+                }
+            }
+        };
+
+        export(&self.trace, Timing::chrome_trace) & export(&self.collapsed, Timing::collapsed)
+    }
+}
+
+/// Solves a part, on its own thread when it has to finish in time. A panic
+/// fails only this part.
+fn solve(
+    day: &Day,
+    part: u8,
+    parsed: &Arc<ParsedInput>,
+    timeout: Option<Duration>,
+) -> Result<Answer, PartError> {
+    let Some(timeout) = timeout else {
+        return runner::catch_panic(|| day.solve(part, parsed));
+    };
+    let (day, parsed) = (day.clone(), parsed.clone());
+    runner::with_timeout(timeout, move || day.solve(part, &parsed))
+}
+
+fn run_day(
+    day: &Day,
+    parts: &[u8],
+    inputs: &Inputs,
+    exports: &Exports,
+    report: bool,
+    timeout: Option<Duration>,
+) -> bool {
+    let Some(input) = inputs.read(day) else {
+        return false;
+    };
+
+    let runner = Runner::start();
+    let parsed = {
+        let _span = runner.span("parse");
+        parse(day, &input)
+    };
+    let Some(parsed) = parsed.map(Arc::new) else {
+        return false;
+    };
+
+    let mut ok = true;
+    for &part in parts {
+        let start = Instant::now();
+        let answer = {
+            let _span = runner.span(format!("part {}", part));
+            solve(day, part, &parsed, timeout)
+        };
+        let elapsed = start.elapsed();
+        match &answer {
+            // Grids start on their own line, so that they line up
+            Ok(answer) if answer.is_multiline() => {
+                println!("Day {:02} part {}:\n{}", day.day, part, answer)
+            }
+            Ok(answer) => println!("Day {:02} part {}: {}", day.day, part, answer),
+            Err(err @ PartError::TimedOut(_)) => {
+                println!("Day {:02} part {}: TIMEOUT, {}", day.day, part, err);
+                ok = false;
+            }
+            Err(err) => {
+                println!("Day {:02} part {}: ERROR, {}", day.day, part, err);
+                ok = false;
+            }
+        }
+        if report {
+            PartReport::new(part, answer, elapsed).print();
+        }
+    }
+
+    exports.write(&runner.end()) && ok
+}
+
+/// Parses a day's input, printing where it is wrong if it is.
+fn parse(day: &Day, input: &str) -> Option<ParsedInput> {
+    match day.parse(input) {
+        Ok(parsed) => Some(parsed),
+        Err(err) => {
+            eprintln!("Day {}: invalid input at {}", day.day, err);
+            None
+        }
+    }
+}
+
+fn bench_day(day: &Day, parts: &[u8], bench: &Bench, input: &str) -> Option<Vec<(Phase, Stats)>> {
+    let parsed = parse(day, input)?;
+    let mut results = vec![(Phase::Parse, bench.run(|| day.parse(input)))];
+    for &part in parts {
+        results.push((Phase::Part(part), bench.run(|| day.solve(part, &parsed))));
+    }
+
+    // Reported at the end, as the days may print a lot while running
+    for (phase, stats) in &results {
+        println!("Day {:02} {:<7} {}", day.day, format!("{}:", phase), stats);
+    }
+
+    Some(results)
+}
+
+/// Prints how each record moved from the baseline, returning false if any of
+/// them got slower than the threshold.
+fn compare(history: &[Record], records: &[Record], baseline: &str, threshold: f64) -> bool {
+    let mut ok = true;
+    for record in records {
+        let label = format!("Day {:02} {:<7}", record.day, format!("{}:", record.phase));
+        let Some(base) = History::baseline(history, baseline, record.day, record.phase) else {
+            println!("{} no '{}' baseline", label, baseline);
+            continue;
+        };
+
+        let change = regression(&base.stats, &record.stats);
+        let regressed = change > threshold;
+        ok &= !regressed;
+        println!(
+            "{} {:+.1}% median vs {} ({}){}",
+            label,
+            change,
+            baseline,
+            base.rev,
+            if regressed { "  REGRESSION" } else { "" }
+        );
+    }
+    ok
+}
+
+struct Check {
+    day: u8,
+    part: u8,
+    expected: String,
+    actual: String,
+    verdict: Option<Verdict>,
+}
+
+/// Solves both parts of a day and checks them against its answers file. A
+/// `None` verdict means the day could not be run at all.
+fn verify_day(day: &Day, inputs: &Inputs, timeout: Option<Duration>) -> Vec<Check> {
+    let answers = Answers::load(answers_path(day.day)).unwrap_or_else(|err| {
+        eprintln!("Day {}: {}", day.day, err);
+        Answers::default()
+    });
+    let input = inputs.read(day);
+    let parsed = input
+        .as_ref()
+        .and_then(|input| parse(day, input))
+        .map(Arc::new);
+
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let expected = answers
+                .get(part)
+                .map_or("-".to_string(), |a| Answer::from(a).inline());
+            let Some(parsed) = &parsed else {
+                return Check {
+                    day: day.day,
+                    part,
+                    expected,
+                    actual: match &input {
+                        Some(_) => "invalid input",
+                        None => "no input",
+                    }
+                    .to_string(),
+                    verdict: None,
+                };
+            };
+
+            let actual = solve(day, part, parsed, timeout);
+            Check {
+                day: day.day,
+                part,
+                expected,
+                verdict: answers.check(part, &actual),
+                actual: match &actual {
+                    Ok(actual) => actual.inline(),
+                    Err(PartError::TimedOut(_)) => "-".to_string(),
+                    Err(PartError::Panicked(_)) => "panicked".to_string(),
+                },
+            }
+        })
+        .collect()
+}
+
+fn print_checks(checks: &[Check]) -> bool {
+    let left = |title| (title, Align::Left);
+    let mut table = Table::new(&[left("Day"), left("Part"), left("Expected"), left("Actual")]);
+    for c in checks {
+        let cells = vec![
+            format!("{:02}", c.day),
+            c.part.to_string(),
+            c.expected.clone(),
+            c.actual.clone(),
+        ];
+        table.push(cells, c.verdict);
+    }
+    table.print();
+    table.ok()
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    part.map(|p| vec![p]).unwrap_or_else(|| vec![1, 2])
+}
+
+fn exit_code(ok: bool) -> ExitCode {
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(filter) = &cli.log {
+        log::set_filter(filter.parse().unwrap());
+    }
+    let registry = days::registry();
+    let inputs = Inputs {
+        dir: cli.inputs,
+        input: cli.input.as_deref().map(InputSource::from_arg),
+    };
+    let select = |days: DaySelector| match days.select(&registry) {
+        Ok(days) if inputs.input.is_some() && days.len() != 1 => {
+            eprintln!("--input can only be used with a single day");
+            None
+        }
+        Ok(days) => Some(days),
+        Err(err) => {
+            eprintln!("{}", err);
+            None
+        }
+    };
+
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            trace,
+            collapsed,
+            jobs,
+            show_output,
+            input_dir,
+            report,
+            timeout,
+        } => {
+            let Some(days) = select(days) else {
+                return ExitCode::FAILURE;
+            };
+            if let Some(dir) = input_dir {
+                if days.len() != 1 {
+                    eprintln!("--input-dir can only be used with a single day");
+                    return ExitCode::FAILURE;
+                }
+                return exit_code(multi::run_inputs(days[0], &parts(part), &dir, timeout));
+            }
+            if let Some(jobs) = jobs {
+                if matches!(inputs.input, Some(InputSource::Stdin)) {
+                    eprintln!("--jobs cannot read the input from stdin");
+                    return ExitCode::FAILURE;
+                }
+                let options = parallel::Options {
+                    part,
+                    timeout,
+                    jobs,
+                    show_output,
+                    log: cli.log.clone(),
+                };
+                return exit_code(parallel::run_days(&days, &inputs, &options));
+            }
+
+            let exports = Exports { trace, collapsed };
+            if days.len() != 1 && (exports.trace.is_some() || exports.collapsed.is_some()) {
+                eprintln!("--trace and --collapsed can only be used with a single day");
+                return ExitCode::FAILURE;
+            }
+            let parts = parts(part);
+
+            let mut ok = true;
+            for day in days {
+                ok &= run_day(day, &parts, &inputs, &exports, report, timeout);
+            }
+
+            exit_code(ok)
+        }
+        Command::Bench {
+            days,
+            part,
+            warmup,
+            runs,
+            budget,
+            save,
+            compare,
+            threshold,
+            history,
+            no_history,
+            size,
+            seed,
+        } => {
+            let Some(days) = select(days) else {
+                return ExitCode::FAILURE;
+            };
+            let parts = parts(part);
+            let bench = Bench {
+                warmup,
+                budget: runs.map(Budget::Runs).unwrap_or(Budget::Time(budget)),
+            };
+
+            let history = History::new(history);
+            let previous = match history.load() {
+                Ok(records) => records,
+                Err(err) => {
+                    eprintln!("Cannot read {}: {}", history.path().display(), err);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let rev = git_rev();
+            let mut ok = true;
+            let mut records = vec![];
+            for day in days {
+                let input = match size {
+                    Some(size) => generate(day, size, seed),
+                    None => inputs.read(day),
+                };
+                let Some(results) = input.and_then(|input| bench_day(day, &parts, &bench, &input))
+                else {
+                    ok = false;
+                    continue;
+                };
+                records.extend(results.into_iter().map(|(phase, stats)| Record {
+                    day: day.day,
+                    phase,
+                    rev: rev.clone(),
+                    label: save.clone().unwrap_or_default(),
+                    stats,
+                }));
+            }
+
+            if let Some(baseline) = compare.as_deref() {
+                println!();
+                ok &= self::compare(&previous, &records, baseline, threshold);
+            }
+
+            // Generated inputs would skew the comparisons with real ones
+            if !no_history && size.is_none() {
+                if let Err(err) = history.append(&records) {
+                    eprintln!("Cannot write {}: {}", history.path().display(), err);
+                    ok = false;
+                }
+            }
+
+            exit_code(ok)
+        }
+        Command::Gen {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let Some(days) = select(DaySelector::Day(day)) else {
+                return ExitCode::FAILURE;
+            };
+            let seed = seed.unwrap_or_else(Generator::random_seed);
+            exit_code(write_generated(days[0], size, seed, output, &inputs))
+        }
+        Command::Diff {
+            day,
+            part,
+            size,
+            count,
+            seed,
+        } => {
+            let Some(days) = select(DaySelector::Day(day)) else {
+                return ExitCode::FAILURE;
+            };
+            let source = match size {
+                Some(size) => diff::Source::Generated {
+                    size,
+                    seeds: (seed..seed.saturating_add(count)).collect(),
+                },
+                None => diff::Source::Real,
+            };
+            exit_code(diff::diff(days[0], &parts(part), source, &inputs))
+        }
+        Command::New { day } => {
+            let config = match Config::load(&cli.config) {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            exit_code(scaffold::new_day(day, &config, &inputs))
+        }
+        Command::Fetch { day } => {
+            let config = match Config::load(&cli.config) {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            exit_code(remote::fetch(day, &config, &inputs))
+        }
+        Command::Submit { day, part } => {
+            let Some(days) = select(DaySelector::Day(day)) else {
+                return ExitCode::FAILURE;
+            };
+            let config = match Config::load(&cli.config) {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            exit_code(remote::submit(days[0], part, &config, &inputs))
+        }
+        Command::Examples { day, page } => {
+            exit_code(extract_examples(day, &InputSource::from_arg(&page)))
+        }
+        Command::Watch { day } => exit_code(watch::watch(day, &inputs, cli.log.as_deref())),
+        Command::Verify { days, timeout } => {
+            let Some(days) = select(days) else {
+                return ExitCode::FAILURE;
+            };
+
+            let checks = days
+                .into_iter()
+                .flat_map(|day| verify_day(day, &inputs, timeout))
+                .collect::<Vec<_>>();
+            exit_code(print_checks(&checks))
+        }
+    }
+}
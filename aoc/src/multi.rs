@@ -0,0 +1,179 @@
+use std::{
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use runner::{input_files, Answer, Answers, Day, InputSource, Verdict};
+
+use crate::{
+    parse, solve,
+    table::{Align, Table},
+};
+
+#[derive(Debug)]
+struct Row {
+    input: String,
+    part: String,
+    answer: String,
+    expected: String,
+    time: String,
+    /// `None` when the input could not be read or parsed
+    verdict: Option<Verdict>,
+}
+
+/// Runs a day against every `*.txt` input in `dir`, checking each one against
+/// the answers file next to it, if any, and ends with a table of the results.
+pub fn run_inputs(day: &Day, parts: &[u8], dir: &Path, timeout: Option<Duration>) -> bool {
+    let files = match input_files(dir) {
+        Ok(files) if files.is_empty() => {
+            eprintln!("No *.txt input in {}", dir.display());
+            return false;
+        }
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("Cannot list the inputs in {}: {}", dir.display(), err);
+            return false;
+        }
+    };
+
+    let mut rows = vec![];
+    for file in &files {
+        let name = file
+            .file_stem()
+            .map_or(String::new(), |stem| stem.to_string_lossy().into_owned());
+        println!("Day {:02} on {}", day.day, file.display());
+        rows.extend(run_input(day, parts, file, &name, timeout));
+    }
+
+    let mut table = Table::new(&[
+        ("Input", Align::Left),
+        ("Part", Align::Left),
+        ("Answer", Align::Left),
+        ("Expected", Align::Left),
+        ("Time", Align::Right),
+    ]);
+    for r in rows {
+        table.push(
+            vec![r.input, r.part, r.answer, r.expected, r.time],
+            r.verdict,
+        );
+    }
+    table.print();
+    table.ok()
+}
+
+fn run_input(
+    day: &Day,
+    parts: &[u8],
+    file: &Path,
+    name: &str,
+    timeout: Option<Duration>,
+) -> Vec<Row> {
+    let answers = Answers::load(Answers::path_for(file)).unwrap_or_else(|err| {
+        eprintln!("Day {}: {}", day.day, err);
+        Answers::default()
+    });
+    let error = |answer: &str| Row {
+        input: name.to_string(),
+        part: "-".to_string(),
+        answer: answer.to_string(),
+        expected: "-".to_string(),
+        time: "-".to_string(),
+        verdict: None,
+    };
+
+    let input = match InputSource::File(file.to_path_buf()).read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Day {}: {}", day.day, err);
+            return vec![error("no input")];
+        }
+    };
+    let Some(parsed) = parse(day, &input).map(Arc::new) else {
+        return vec![error("invalid input")];
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solve(day, part, &parsed, timeout);
+            let elapsed = start.elapsed();
+            Row {
+                input: name.to_string(),
+                part: part.to_string(),
+                answer: answer.as_ref().map_or("-".to_string(), Answer::inline),
+                expected: answers
+                    .get(part)
+                    .map_or("-".to_string(), |a| Answer::from(a).inline()),
+                time: format!("{:.3}ms", elapsed.as_secs_f64() * 1_000.0),
+                verdict: answers.check(part, &answer),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use runner::{ParseError, Solution};
+
+    use super::*;
+
+    /// Sums the numbers of the input, one per line.
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+        }
+
+        fn part1(numbers: &Self::Input) -> impl Into<Answer> {
+            numbers.iter().sum::<i64>()
+        }
+
+        fn part2(_: &Self::Input) -> impl Into<Answer> {}
+    }
+
+    #[test]
+    fn test_run_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-multi-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("alice.txt"), "1\n2\n").unwrap();
+        fs::write(dir.join("alice.answers.toml"), "part1 = 3\n").unwrap();
+        fs::write(dir.join("bob.txt"), "4\n").unwrap();
+        fs::write(dir.join("bob.answers.toml"), "part1 = 3\n").unwrap();
+        fs::write(dir.join("carol.txt"), "5\n").unwrap();
+
+        let day = Day::new::<Sum>();
+        let rows = input_files(&dir)
+            .unwrap()
+            .iter()
+            .flat_map(|file| {
+                let name = file.file_stem().unwrap().to_string_lossy().into_owned();
+                run_input(&day, &[1], file, &name, None)
+            })
+            .map(|r| (r.input, r.answer, r.expected, r.verdict))
+            .collect::<Vec<_>>();
+        let row = |input: &str, answer: &str, expected: &str, verdict| {
+            let (input, answer, expected) = (input.into(), answer.into(), expected.into());
+            (input, answer, expected, Some(verdict))
+        };
+        assert_eq!(
+            rows,
+            vec![
+                row("alice", "3", "3", Verdict::Pass),
+                row("bob", "4", "3", Verdict::Fail),
+                row("carol", "5", "-", Verdict::Missing),
+            ]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    env,
+    process::Command,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use runner::{Answer, Answers, Day, InputSource, PartError};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answers_path,
+    table::{Align, Table},
+    Inputs,
+};
+
+/// Marks the lines `run --report` adds to the output of a day, so they can be
+/// told apart from whatever the day prints.
+const REPORT_PREFIX: &str = "@aoc-report ";
+
+/// The result of a part, as reported by a child process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<Answer, PartError>,
+    pub nanos: u64,
+}
+
+impl PartReport {
+    pub fn new(part: u8, answer: Result<Answer, PartError>, elapsed: Duration) -> Self {
+        Self {
+            part,
+            answer,
+            nanos: elapsed.as_nanos() as u64,
+        }
+    }
+
+    pub fn print(&self) {
+        println!("{}{}", REPORT_PREFIX, serde_json::to_string(self).unwrap());
+    }
+
+    /// The answer on a single line, `-` if there is none.
+    pub fn inline(&self) -> String {
+        self.answer.as_ref().map_or("-".to_string(), Answer::inline)
+    }
+}
+
+/// Everything a day's process printed, with its reports taken out.
+struct DayOutput {
+    day: u8,
+    reports: Vec<PartReport>,
+    stdout: String,
+    stderr: String,
+    success: bool,
+}
+
+/// How `run --jobs` runs the days, as set on the command line.
+pub struct Options {
+    pub part: Option<u8>,
+    pub timeout: Option<Duration>,
+    pub jobs: usize,
+    pub show_output: bool,
+    pub log: Option<String>,
+}
+
+/// Runs every day in its own `aoc run --report` process, `jobs` at a time,
+/// then prints a summary table checked against the known answers. Returns
+/// false if any day failed, timed out or gave a wrong answer.
+pub fn run_days(days: &[&Day], inputs: &Inputs, options: &Options) -> bool {
+    let start = Instant::now();
+    let next = AtomicUsize::new(0);
+    let outputs = Mutex::new(Vec::with_capacity(days.len()));
+
+    thread::scope(|s| {
+        for _ in 0..options.jobs.clamp(1, days.len().max(1)) {
+            s.spawn(|| {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let output = run_child(day.day, inputs, options);
+                    eprintln!(
+                        "Day {:02} {}",
+                        output.day,
+                        if output.success { "done" } else { "FAILED" }
+                    );
+                    outputs.lock().unwrap().push(output);
+                }
+            });
+        }
+    });
+
+    let mut outputs = outputs.into_inner().unwrap();
+    outputs.sort_by_key(|o| o.day);
+
+    for output in &outputs {
+        // The output of failed days is always shown, as it tells why
+        if options.show_output || !output.success {
+            println!("\n--- Day {:02} ---", output.day);
+            print!("{}", output.stdout);
+            eprint!("{}", output.stderr);
+        }
+    }
+
+    let ok = print_summary(&outputs);
+    println!(
+        "Wall time: {:.3}ms",
+        start.elapsed().as_secs_f64() * 1_000.0
+    );
+    ok
+}
+
+fn run_child(day: u8, inputs: &Inputs, options: &Options) -> DayOutput {
+    let mut command = Command::new(env::current_exe().unwrap());
+    command
+        .args(["run", &day.to_string(), "--report", "--inputs"])
+        .arg(&inputs.dir);
+    if let Some(part) = options.part {
+        command.args(["--part", &part.to_string()]);
+    }
+    if let Some(timeout) = options.timeout {
+        command
+            .arg("--timeout")
+            .arg(format!("{}ms", timeout.as_millis()));
+    }
+    if let Some(filter) = &options.log {
+        command.args(["--log", filter]);
+    }
+    if let Some(InputSource::File(path)) = &inputs.input {
+        command.arg("--input").arg(path);
+    }
+
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(err) => {
+            return DayOutput {
+                day,
+                reports: vec![],
+                stdout: String::new(),
+                stderr: format!("Cannot start the process: {}\n", err),
+                success: false,
+            }
+        }
+    };
+
+    let (reports, stdout) = split_reports(&output.stdout);
+    DayOutput {
+        day,
+        reports,
+        stdout,
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        success: output.status.success(),
+    }
+}
+
+/// Takes the reports out of what `run --report` printed, returning them with
+/// the rest of the output.
+pub fn split_reports(output: &[u8]) -> (Vec<PartReport>, String) {
+    let mut reports = vec![];
+    let mut rest = String::new();
+    for line in String::from_utf8_lossy(output).lines() {
+        match line.strip_prefix(REPORT_PREFIX).map(serde_json::from_str) {
+            Some(Ok(report)) => reports.push(report),
+            _ => {
+                rest.push_str(line);
+                rest.push('\n');
+            }
+        }
+    }
+    (reports, rest)
+}
+
+fn print_summary(outputs: &[DayOutput]) -> bool {
+    let mut table = Table::new(&[
+        ("Day", Align::Left),
+        ("Part", Align::Left),
+        ("Answer", Align::Left),
+        ("Time", Align::Right),
+    ]);
+    for output in outputs {
+        let day = format!("{:02}", output.day);
+        if output.reports.is_empty() {
+            let cells = vec![day, "-".to_string(), "-".to_string(), "-".to_string()];
+            table.push(cells, None);
+            continue;
+        }
+
+        let answers = Answers::load(answers_path(output.day)).unwrap_or_default();
+        for report in &output.reports {
+            let cells = vec![
+                day.clone(),
+                report.part.to_string(),
+                report.inline(),
+                format!("{:.3}ms", report.nanos as f64 / 1_000_000.0),
+            ];
+            table.push(cells, answers.check(report.part, &report.answer));
+        }
+    }
+    table.print();
+
+    let total = outputs
+        .iter()
+        .flat_map(|o| &o.reports)
+        .map(|r| Duration::from_nanos(r.nanos))
+        .sum::<Duration>();
+    println!("Total time: {:.3}ms", total.as_secs_f64() * 1_000.0);
+    table.ok() && outputs.iter().all(|o| o.success)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_reports() {
+        let answer = PartReport::new(1, Ok(Answer::from(42)), Duration::from_micros(3));
+        let panicked = PartReport::new(
+            2,
+            Err(PartError::Panicked("oops".to_string())),
+            Duration::ZERO,
+        );
+        let output = [
+            "Day 12: parsing".to_string(),
+            format!(
+                "{}{}",
+                REPORT_PREFIX,
+                serde_json::to_string(&answer).unwrap()
+            ),
+            "  a line the day printed".to_string(),
+            format!(
+                "{}{}",
+                REPORT_PREFIX,
+                serde_json::to_string(&panicked).unwrap()
+            ),
+            format!("{}not json", REPORT_PREFIX),
+        ]
+        .join("\n");
+
+        let (reports, rest) = split_reports(output.as_bytes());
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].part, 1);
+        assert_eq!(reports[0].answer, Ok(Answer::Number(42)));
+        assert_eq!(reports[0].nanos, 3_000);
+        assert_eq!(reports[1].inline(), "-");
+        assert_eq!(
+            rest,
+            format!(
+                "Day 12: parsing\n  a line the day printed\n{}not json\n",
+                REPORT_PREFIX
+            )
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::{fs, path::Path};
+
+use runner::{Answer, Answers, Day, InputSource};
+
+use crate::{answers_path, client::Outcome, config::Config, parse, workspace_path, Inputs};
+
+/// Downloads the input of a day into the cache and copies it to where `run`
+/// reads it, unless it is already there.
+pub fn fetch(day: u8, config: &Config, inputs: &Inputs) -> bool {
+    if let InputSource::File(path) = inputs.source(day) {
+        if path.exists() {
+            println!("Day {}: input already at {}", day, path.display());
+            return true;
+        }
+    }
+
+    let cache = config.cache(workspace_path(".aoc-cache"));
+    let cached = match cache.input(day, || config.client(&cache)) {
+        Ok((path, true)) => {
+            println!("Day {}: downloaded to {}", day, path.display());
+            path
+        }
+        Ok((path, false)) => {
+            println!("Day {}: already cached at {}", day, path.display());
+            path
+        }
+        Err(err) => {
+            eprintln!("Day {}: cannot fetch the input: {}", day, err);
+            return false;
+        }
+    };
+
+    copy_input(day, &cached, inputs)
+}
+
+/// Copies a cached input to where `run` reads it, unless something is
+/// already there.
+pub fn copy_input(day: u8, cached: &Path, inputs: &Inputs) -> bool {
+    let InputSource::File(target) = inputs.source(day) else {
+        return true;
+    };
+    if target.exists() {
+        return true;
+    }
+    let copied = target
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::copy(cached, &target));
+    match copied {
+        Ok(_) => {
+            println!("Day {}: copied to {}", day, target.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("Day {}: cannot copy to {}: {}", day, target.display(), err);
+            false
+        }
+    }
+}
+
+/// Solves a part and posts the answer, unless it is already known to be
+/// right or wrong, then records the outcome in the day's answers file.
+pub fn submit(day: &Day, part: u8, config: &Config, inputs: &Inputs) -> bool {
+    let path = answers_path(day.day);
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Day {}: {}", day.day, err);
+            return false;
+        }
+    };
+    if let Some(known) = answers.get(part) {
+        println!(
+            "Day {:02} part {} is already solved: {}",
+            day.day, part, known
+        );
+        return true;
+    }
+
+    let Some(input) = inputs.read(day) else {
+        return false;
+    };
+    let Some(parsed) = parse(day, &input) else {
+        return false;
+    };
+    let answer = match day.solve(part, &parsed) {
+        Answer::Unsolved => {
+            eprintln!("Day {:02} part {} is not solved yet", day.day, part);
+            return false;
+        }
+        answer if answer.is_multiline() => {
+            eprintln!("Not submitting, the answer must fit on a line:\n{}", answer);
+            return false;
+        }
+        answer => answer.to_string(),
+    };
+    println!("Day {:02} part {}: {}", day.day, part, answer);
+
+    if let Some(reason) = answers.known_wrong(part, &answer) {
+        eprintln!("Not submitting {}: {}", answer, reason);
+        return false;
+    }
+
+    let cache = config.cache(workspace_path(".aoc-cache"));
+    let outcome = match config
+        .client(&cache)
+        .and_then(|client| client.submit(day.day, part, &answer))
+    {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            eprintln!("Day {}: cannot submit: {}", day.day, err);
+            return false;
+        }
+    };
+    println!("{}", outcome);
+
+    match outcome {
+        Outcome::Correct => answers.set(part, &answer),
+        Outcome::Wrong(hint) => answers.add_wrong(part, &answer, hint),
+        _ => return false,
+    }
+    if let Err(err) = answers.save(&path) {
+        eprintln!("Cannot write {}: {}", path.display(), err);
+        return false;
+    }
+    outcome == Outcome::Correct
+}
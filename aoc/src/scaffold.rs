@@ -0,0 +1,230 @@
+use std::{fs, io, path::Path};
+
+use crate::{config::Config, remote, workspace_path, Inputs};
+
+/// Creates `day-xx` from the template and registers it, then copies its input
+/// if it is already cached. Refuses to touch a day that exists.
+pub fn new_day(day: u8, config: &Config, inputs: &Inputs) -> bool {
+    if let Err(err) = create_day(&workspace_path(""), day) {
+        eprintln!("{}", err);
+        return false;
+    }
+    println!("Created day-{:02} and registered Day{:02}", day, day);
+
+    let cached = config.cache(workspace_path(".aoc-cache")).input_path(day);
+    if cached.exists() {
+        return remote::copy_input(day, &cached, inputs);
+    }
+    println!("Input not cached yet, get it with `aoc fetch {}`", day);
+    true
+}
+
+/// Creates `day-xx` in `workspace` and registers it with the `aoc` crate.
+fn create_day(workspace: &Path, day: u8) -> Result<(), String> {
+    let name = format!("day-{:02}", day);
+    let dir = workspace.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Everything is checked before anything is written
+    let manifest_path = workspace.join("aoc/Cargo.toml");
+    let days_path = workspace.join("aoc/src/days.rs");
+    let edited = fs::read_to_string(&manifest_path).and_then(|manifest| {
+        let days = fs::read_to_string(&days_path)?;
+        Ok((add_dependency(&manifest, day), register(&days, day)))
+    });
+    let (manifest, days) = match edited {
+        Ok((Some(manifest), Some(days))) => (manifest, days),
+        Ok(_) => {
+            return Err(format!(
+                "Day {} is already registered, or the registry is not where expected",
+                day
+            ))
+        }
+        Err(err) => return Err(format!("Cannot read the registry: {}", err)),
+    };
+
+    copy_template(&workspace.join("template"), &dir, day)
+        .and_then(|_| fs::write(&manifest_path, manifest))
+        .and_then(|_| fs::write(&days_path, days))
+        .map_err(|err| format!("Cannot create {}: {}", name, err))
+}
+
+/// Copies the template, filling in `{{crate}}`, `{{name}}` and `{{day}}`.
+fn copy_template(from: &Path, to: &Path, day: u8) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &target, day)?;
+            continue;
+        }
+        let content = fs::read_to_string(entry.path())?
+            .replace("{{crate}}", &format!("day-{:02}", day))
+            .replace("{{name}}", &format!("Day{:02}", day))
+            .replace("{{day}}", &day.to_string());
+        fs::write(target, content)?;
+    }
+    Ok(())
+}
+
+fn add_dependency(manifest: &str, day: u8) -> Option<String> {
+    let line = format!("day-{:02} = {{ path = \"../day-{:02}\" }}", day, day);
+    insert_day(manifest, day, &line, "", |line| {
+        line.strip_prefix("day-")?.get(..2)?.parse().ok()
+    })
+}
+
+fn register(days: &str, day: u8) -> Option<String> {
+    let line = format!("        .register::<day_{:02}::Day{:02}>()", day, day);
+    insert_day(days, day, &line, ";", |line| {
+        let line = line.trim_start().strip_prefix(".register::<day_")?;
+        line.get(..2)?.parse().ok()
+    })
+}
+
+/// Inserts `line` among the lines `day_of` recognizes, keeping them sorted by
+/// day. Whatever ends the last of them (`;` closing a method chain) moves to
+/// the new last one. Returns `None` if the day is already there.
+fn insert_day(
+    source: &str,
+    day: u8,
+    line: &str,
+    end: &str,
+    day_of: fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let mut lines = source.lines().map(str::to_string).collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line).map(|d| (i, d)))
+        .collect::<Vec<_>>();
+    let &(last, _) = days.last()?;
+    if days.iter().any(|&(_, d)| d == day) {
+        return None;
+    }
+
+    let index = days
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(index, line.to_string());
+    if let Some(previous) = lines[last].trim_end().strip_suffix(end) {
+        if index == last + 1 {
+            lines[last] = previous.to_string();
+            lines[index].push_str(end);
+        }
+    }
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    const MANIFEST: &str = "[dependencies]\n\
+        clap = \"4\"\n\
+        day-01 = { path = \"../day-01\" }\n\
+        day-03 = { path = \"../day-03\" }\n";
+
+    const DAYS: &str = "pub fn registry() -> Registry {\n\
+        \x20   let mut registry = Registry::new();\n\
+        \x20   registry\n\
+        \x20       .register::<day_01::Day01>()\n\
+        \x20       .register::<day_03::Day03>();\n\
+        \x20   registry\n\
+        }\n";
+
+    /// A workspace holding days 1 and 3, with a one file template.
+    fn workspace(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("aoc/src")).unwrap();
+        fs::create_dir_all(dir.join("template/src")).unwrap();
+        fs::write(dir.join("aoc/Cargo.toml"), MANIFEST).unwrap();
+        fs::write(dir.join("aoc/src/days.rs"), DAYS).unwrap();
+        fs::write(
+            dir.join("template/src/lib.rs"),
+            "// {{crate}}\npub struct {{name}};\nconst DAY: u8 = {{day}};\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_create_day() {
+        let dir = workspace("scaffold");
+        create_day(&dir, 2).unwrap();
+        create_day(&dir, 4).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.join("day-02/src/lib.rs")).unwrap(),
+            "// day-02\npub struct Day02;\nconst DAY: u8 = 2;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\n\
+            clap = \"4\"\n\
+            day-01 = { path = \"../day-01\" }\n\
+            day-02 = { path = \"../day-02\" }\n\
+            day-03 = { path = \"../day-03\" }\n\
+            day-04 = { path = \"../day-04\" }\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("aoc/src/days.rs")).unwrap(),
+            "pub fn registry() -> Registry {\n\
+            \x20   let mut registry = Registry::new();\n\
+            \x20   registry\n\
+            \x20       .register::<day_01::Day01>()\n\
+            \x20       .register::<day_02::Day02>()\n\
+            \x20       .register::<day_03::Day03>()\n\
+            \x20       .register::<day_04::Day04>();\n\
+            \x20   registry\n\
+            }\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_existing_day() {
+        let dir = workspace("scaffold-existing");
+        fs::create_dir(dir.join("day-05")).unwrap();
+        let err = create_day(&dir, 5).unwrap_err();
+        assert!(err.ends_with("day-05 already exists"), "{}", err);
+
+        // Registered without its directory, nothing is written either
+        let err = create_day(&dir, 3).unwrap_err();
+        assert!(err.starts_with("Day 3 is already registered"), "{}", err);
+        assert!(!dir.join("day-03").exists());
+        assert_eq!(
+            fs::read_to_string(dir.join("aoc/Cargo.toml")).unwrap(),
+            MANIFEST
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("aoc/src/days.rs")).unwrap(),
+            DAYS
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_insert_day() {
+        let manifest = add_dependency(MANIFEST, 2).unwrap();
+        assert_eq!(add_dependency(&manifest, 2), None);
+        assert_eq!(add_dependency(&manifest, 1), None);
+
+        let days = register(DAYS, 4).unwrap();
+        assert_eq!(register(&days, 4), None);
+        assert!(days.contains("<day_03::Day03>()\n        .register::<day_04::Day04>();\n"));
+        assert_eq!(register("no registry here\n", 4), None);
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::{self, Display};
+
+use runner::Verdict;
+
+/// How a column lines up its cells.
+#[derive(Debug, Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Checked answers, shown as a table ending with a status column and followed
+/// by a count of each verdict. A row without a verdict is a part that could
+/// not be run.
+pub struct Table {
+    columns: Vec<(&'static str, Align)>,
+    rows: Vec<(Vec<String>, Option<Verdict>)>,
+}
+
+impl Table {
+    pub fn new(columns: &[(&'static str, Align)]) -> Self {
+        Self {
+            columns: columns.to_vec(),
+            rows: vec![],
+        }
+    }
+
+    /// Adds a row, with one cell per column.
+    pub fn push(&mut self, cells: Vec<String>, verdict: Option<Verdict>) {
+        debug_assert_eq!(cells.len(), self.columns.len());
+        self.rows.push((cells, verdict));
+    }
+
+    /// Whether every part passed or has no answer to be checked against.
+    pub fn ok(&self) -> bool {
+        self.rows
+            .iter()
+            .all(|(_, v)| matches!(v, Some(Verdict::Pass | Verdict::Missing)))
+    }
+
+    pub fn print(&self) {
+        // Days may print a lot while running, keep the table apart
+        println!();
+        print!("{}", self);
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, (title, _))| {
+                let cells = self.rows.iter().map(|(cells, _)| cells[i].len());
+                cells.max().unwrap_or(0).max(title.len())
+            })
+            .collect::<Vec<_>>();
+        let line = |f: &mut fmt::Formatter<'_>, cells: &[&str], status: &str| {
+            for ((cell, &width), (_, align)) in cells.iter().zip(&widths).zip(&self.columns) {
+                match align {
+                    Align::Left => write!(f, "{:<width$}  ", cell)?,
+                    Align::Right => write!(f, "{:>width$}  ", cell)?,
+                }
+            }
+            writeln!(f, "{}", status)
+        };
+
+        let titles = self.columns.iter().map(|(title, _)| *title);
+        line(f, &titles.collect::<Vec<_>>(), "Status")?;
+        for (cells, verdict) in &self.rows {
+            let cells = cells.iter().map(String::as_str).collect::<Vec<_>>();
+            let status = verdict.map_or("error".to_string(), |v| v.to_string());
+            line(f, &cells, &status)?;
+        }
+
+        let count = |v: Verdict| self.rows.iter().filter(|(_, r)| *r == Some(v)).count();
+        writeln!(
+            f,
+            "\n{} passed, {} failed, {} missing, {} timed out, {} errors",
+            count(Verdict::Pass),
+            count(Verdict::Fail),
+            count(Verdict::Missing),
+            count(Verdict::Timeout),
+            self.rows.iter().filter(|(_, v)| v.is_none()).count()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let mut table = Table::new(&[("Day", Align::Left), ("Time", Align::Right)]);
+        table.push(
+            vec!["01".to_string(), "1.500ms".to_string()],
+            Some(Verdict::Pass),
+        );
+        assert!(table.ok());
+        table.push(vec!["12".to_string(), "-".to_string()], None);
+        assert!(!table.ok());
+
+        assert_eq!(
+            table.to_string(),
+            "Day     Time  Status\n\
+             01   1.500ms  pass\n\
+             12         -  error\n\
+             \n\
+             1 passed, 0 failed, 0 missing, 0 timed out, 1 errors\n"
+        );
+    }
+}
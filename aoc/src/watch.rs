@@ -0,0 +1,398 @@
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::Duration,
+};
+
+use inotify::{EventMask, Inotify, WatchDescriptor, WatchMask};
+use runner::{Answers, InputSource, EXAMPLES_FILE};
+
+use crate::{
+    answers_path,
+    parallel::{self, PartReport},
+    workspace_path, Inputs,
+};
+
+/// How long to wait for more changes after one, so that saving several files
+/// at once rebuilds only once.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// What a directory is watched for.
+enum Watched {
+    /// Any Rust file, and new directories to watch too
+    Sources(PathBuf),
+    /// `test*.txt`, `example*.txt` and the examples manifest
+    Examples,
+    /// The input, by file name
+    Input(OsString),
+}
+
+impl Watched {
+    fn matches(&self, name: &OsStr) -> bool {
+        let name = name.to_string_lossy();
+        match self {
+            Watched::Sources(_) => name.ends_with(".rs"),
+            Watched::Examples => {
+                (name.starts_with("test") || name.starts_with("example")) && name.ends_with(".txt")
+                    || name == EXAMPLES_FILE
+            }
+            Watched::Input(input) => *name == input.to_string_lossy(),
+        }
+    }
+}
+
+struct Watcher {
+    inotify: Inotify,
+    watches: Vec<(WatchDescriptor, Watched)>,
+    buffer: Vec<u8>,
+}
+
+impl Watcher {
+    fn new(day: u8, input: &Path) -> io::Result<Self> {
+        let mut watcher = Self {
+            inotify: Inotify::init()?,
+            watches: vec![],
+            buffer: vec![0; 4096],
+        };
+
+        let dir = workspace_path(&format!("day-{:02}", day));
+        watcher.add_sources(&dir.join("src"))?;
+        watcher.add(&dir, Watched::Examples)?;
+        // Editors often replace files, so the input is watched through its
+        // directory, which may well be the day's one
+        let name = input.file_name().unwrap_or_default().to_owned();
+        watcher.add(
+            input.parent().unwrap_or(Path::new(".")),
+            Watched::Input(name),
+        )?;
+        Ok(watcher)
+    }
+
+    fn add(&mut self, dir: &Path, watched: Watched) -> io::Result<()> {
+        let mut mask = WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO | WatchMask::DELETE;
+        if let Watched::Sources(_) = watched {
+            mask |= WatchMask::CREATE;
+        }
+        let wd = self.inotify.watches().add(dir, mask)?;
+        self.watches.push((wd, watched));
+        Ok(())
+    }
+
+    /// Watches `dir` and every directory below it, as inotify does not.
+    /// Directories created later are added as they show up.
+    fn add_sources(&mut self, dir: &Path) -> io::Result<()> {
+        self.add(dir, Watched::Sources(dir.to_path_buf()))?;
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                self.add_sources(&entry.path())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Blocks until a watched file changes, then until the changes settle,
+    /// and returns the names of the changed files.
+    fn wait(&mut self) -> io::Result<Vec<String>> {
+        let mut changed = vec![];
+        while changed.is_empty() {
+            changed = self.read(true)?;
+        }
+        loop {
+            thread::sleep(DEBOUNCE);
+            let more = self.read(false)?;
+            if more.is_empty() {
+                break;
+            }
+            changed.extend(more);
+        }
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+
+    fn read(&mut self, blocking: bool) -> io::Result<Vec<String>> {
+        let events = match blocking {
+            true => self.inotify.read_events_blocking(&mut self.buffer)?,
+            false => match self.inotify.read_events(&mut self.buffer) {
+                Ok(events) => events,
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => return Ok(vec![]),
+                Err(err) => return Err(err),
+            },
+        };
+
+        let watches = &self.watches;
+        let mut new_dirs = vec![];
+        let changed = events
+            .filter_map(|event| {
+                let name = event.name?;
+                let is_dir = event.mask.contains(EventMask::ISDIR);
+                // Created files are reported again once written
+                if event.mask.contains(EventMask::CREATE) && !is_dir {
+                    return None;
+                }
+                let added = EventMask::CREATE | EventMask::MOVED_TO;
+                let new_dir = is_dir && event.mask.intersects(added);
+                watches
+                    .iter()
+                    .filter(|(wd, _)| *wd == event.wd)
+                    .any(|(_, watched)| match watched {
+                        // It may already hold files, written before it is watched
+                        Watched::Sources(dir) if new_dir => {
+                            new_dirs.push(dir.join(name));
+                            true
+                        }
+                        watched => watched.matches(name),
+                    })
+                    .then(|| name.to_string_lossy().into_owned())
+            })
+            .collect();
+
+        for dir in new_dirs {
+            if let Err(err) = self.add_sources(&dir) {
+                eprintln!("Cannot watch {}: {}", dir.display(), err);
+            }
+        }
+        Ok(changed)
+    }
+}
+
+/// Reruns the examples of a day, then the day on its input, every time its
+/// sources, examples or input change. Only returns on errors.
+pub fn watch(day: u8, inputs: &Inputs, log: Option<&str>) -> bool {
+    let input = match inputs.source(day) {
+        InputSource::File(path) => path,
+        InputSource::Stdin => {
+            eprintln!("watch cannot read the input from stdin");
+            return false;
+        }
+    };
+    let mut watcher = match Watcher::new(day, &input) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("Cannot watch day {}: {}", day, err);
+            return false;
+        }
+    };
+
+    println!("Watching day {:02}, Ctrl-C to stop", day);
+    let mut previous = vec![];
+    loop {
+        if run_examples(day) {
+            previous = run_input(day, inputs, log, &previous);
+        }
+
+        match watcher.wait() {
+            Ok(changed) => println!("\n--- Changed: {} ---", changed.join(", ")),
+            Err(err) => {
+                eprintln!("Cannot watch day {}: {}", day, err);
+                return false;
+            }
+        }
+    }
+}
+
+fn cargo() -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()));
+    command.current_dir(workspace_path(""));
+    command
+}
+
+/// Runs the example tests of the day, returning false if it does not build.
+fn run_examples(day: u8) -> bool {
+    let output = cargo()
+        .args(["test", "-q", "-p", &format!("day-{:02}", day), "--lib"])
+        .args(["example", "--", "--format", "pretty"])
+        // The assertion says enough
+        .env("RUST_BACKTRACE", "0")
+        .output();
+    let output = match output {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("Cannot run cargo: {}", err);
+            return false;
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (passed, failed): (Vec<_>, Vec<_>) = stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("test "))
+        .filter_map(|line| line.rsplit_once(" ... "))
+        .partition(|(_, result)| *result == "ok");
+
+    if passed.is_empty() && failed.is_empty() {
+        if output.status.success() {
+            println!("examples  none");
+            return true;
+        }
+        print_errors(&String::from_utf8_lossy(&output.stderr));
+        return false;
+    }
+
+    match failed.is_empty() {
+        true => println!("examples  {} passed", passed.len()),
+        false => {
+            print_failures(&stdout);
+            let names = failed.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            println!(
+                "examples  {} passed, {} FAILED: {}",
+                passed.len(),
+                failed.len(),
+                names.join(", ")
+            );
+        }
+    }
+    true
+}
+
+/// Prints the compiler errors, leaving out the warnings.
+fn print_errors(stderr: &str) {
+    for block in stderr.split("\n\n") {
+        if block.trim_start().starts_with("error") {
+            eprintln!("{}\n", block.trim_end());
+        }
+    }
+}
+
+/// Prints why the tests failed: what libtest lists between its two
+/// `failures:` headers.
+fn print_failures(stdout: &str) {
+    let failures = stdout
+        .split("\nfailures:\n")
+        .nth(1)
+        .unwrap_or_default()
+        .trim();
+    if !failures.is_empty() {
+        eprintln!("{}\n", failures);
+    }
+}
+
+/// Runs the day on its input, printing each answer next to its previous one,
+/// and returns the answers.
+fn run_input(
+    day: u8,
+    inputs: &Inputs,
+    log: Option<&str>,
+    previous: &[PartReport],
+) -> Vec<PartReport> {
+    let mut command = cargo();
+    command
+        .args(["run", "-q", "--release", "-p", "aoc", "--"])
+        .args(["run", &day.to_string(), "--report", "--inputs"])
+        .arg(&inputs.dir);
+    if let Some(InputSource::File(path)) = &inputs.input {
+        command.arg("--input").arg(path);
+    }
+    if let Some(filter) = log {
+        command.args(["--log", filter]);
+    }
+
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("Cannot run cargo: {}", err);
+            return previous.to_vec();
+        }
+    };
+    let (reports, _) = parallel::split_reports(&output.stdout);
+    if !output.status.success() || reports.is_empty() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        println!("input     FAILED");
+        return previous.to_vec();
+    }
+
+    let answers = Answers::load(answers_path(day)).unwrap_or_default();
+    for report in &reports {
+        println!("{}", report_line(report, &answers, previous));
+    }
+    reports
+}
+
+/// A part's answer, verdict and time, followed by its previous answer if it
+/// changed.
+fn report_line(report: &PartReport, answers: &Answers, previous: &[PartReport]) -> String {
+    let answer = report.inline();
+    let verdict = answers
+        .check(report.part, &report.answer)
+        .map_or("error".to_string(), |v| v.to_string());
+    let before = previous
+        .iter()
+        .find(|p| p.part == report.part)
+        .map(PartReport::inline);
+    let change = match before {
+        Some(before) if before != answer => format!("  (was {})", before),
+        _ => String::new(),
+    };
+    format!(
+        "part {}    {}  {}  {:.3}ms{}",
+        report.part,
+        answer,
+        verdict,
+        report.nanos as f64 / 1_000_000.0,
+        change
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use runner::{Answer, PartError};
+
+    use super::*;
+
+    #[test]
+    fn test_new_source_dir() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut watcher = Watcher {
+            inotify: Inotify::init().unwrap(),
+            watches: vec![],
+            buffer: vec![0; 4096],
+        };
+        watcher.add_sources(&dir).unwrap();
+
+        fs::create_dir(dir.join("grid")).unwrap();
+        let created = watcher.read(false).unwrap();
+        fs::write(dir.join("grid/mod.rs"), "").unwrap();
+        fs::write(dir.join("notes.txt"), "").unwrap();
+        let written = watcher.read(false).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(created, vec!["grid"]);
+        assert_eq!(written, vec!["mod.rs"]);
+    }
+
+    #[test]
+    fn test_report_line() {
+        let mut answers = Answers::default();
+        answers.set(1, "42");
+        let report = |part, answer: Result<i64, PartError>| {
+            let answer = answer.map(Answer::from);
+            PartReport::new(part, answer, Duration::from_micros(1500))
+        };
+        let previous = [report(1, Ok(41)), report(2, Ok(7))];
+
+        assert_eq!(
+            report_line(&report(1, Ok(42)), &answers, &previous),
+            "part 1    42  pass  1.500ms  (was 41)"
+        );
+        assert_eq!(
+            report_line(&report(2, Ok(7)), &answers, &previous),
+            "part 2    7  missing  1.500ms"
+        );
+        assert_eq!(
+            report_line(&report(1, Ok(42)), &answers, &[]),
+            "part 1    42  pass  1.500ms"
+        );
+
+        let panicked = report(2, Err(PartError::Panicked("oops".to_string())));
+        assert_eq!(
+            report_line(&panicked, &answers, &previous),
+            "part 2    -  error  1.500ms  (was 7)"
+        );
+    }
+}
@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<part1::Calibration>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_calibrations(input)
+    }
+
+    fn part1(calibrations: &Self::Input) -> impl Into<Answer> {
+        part1::sum_all_lines(calibrations)
+    }
+
+    fn part2(calibrations: &Self::Input) -> impl Into<Answer> {
+        part2::sum_string_tokens(calibrations)
+    }
+}
+
+runner::example_tests!(Day01);
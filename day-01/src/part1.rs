@@ -1,26 +1,45 @@
 use itertools::Itertools;
+use toolkit::parse::ParseError;
 
-pub fn resolve_line(line: &str) -> i32 {
-    let chars: Vec<char> = line.chars().collect();
-    let first = chars.iter().find_map(|&c| c.to_digit(10));
-    let last = chars.iter().rev().find_map(|&c| c.to_digit(10));
-    format!("{}{}", first.unwrap(), last.unwrap())
-        .parse::<i32>()
-        .unwrap()
+use crate::part2::digit_at;
+
+/// The first and last digits of a line, as written and counting the spelled
+/// out ones too. Lines with only spelled out digits, like in the example of
+/// part 2, have no written ones.
+pub struct Calibration {
+    pub written: Option<(u32, u32)>,
+    pub spelled: (u32, u32),
 }
 
-pub fn sum_all_lines(input: &str) -> i32 {
-    let lines = input.lines().map(resolve_line).collect_vec();
-    lines.iter().sum()
+fn first_and_last(digits: &[u32]) -> Option<(u32, u32)> {
+    Some((*digits.first()?, *digits.last()?))
 }
 
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
+pub fn parse_calibrations(input: &str) -> Result<Vec<Calibration>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let written = line.chars().filter_map(|c| c.to_digit(10)).collect_vec();
+            let spelled = line
+                .char_indices()
+                .filter_map(|(i, _)| digit_at(&line[i..]))
+                .collect_vec();
+            let spelled = first_and_last(&spelled)
+                .ok_or_else(|| ParseError::at(input, line, "a line with a digit"))?;
+
+            Ok(Calibration {
+                written: first_and_last(&written),
+                spelled,
+            })
+        })
+        .collect()
+}
 
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(sum_all_lines(input), 142);
-    }
+/// Lines without a written digit do not count.
+pub fn sum_all_lines(calibrations: &[Calibration]) -> u32 {
+    calibrations
+        .iter()
+        .filter_map(|c| c.written)
+        .map(|(first, last)| first * 10 + last)
+        .sum()
 }
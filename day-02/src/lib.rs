@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<part1::Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_input(input)
+    }
+
+    fn part1(games: &Self::Input) -> impl Into<Answer> {
+        part1::sum_possible_ids(games)
+    }
+
+    fn part2(games: &Self::Input) -> impl Into<Answer> {
+        part2::count_fewest_cubes(games)
+    }
+}
+
+runner::example_tests!(Day02);
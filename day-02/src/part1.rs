@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use toolkit::parse::{expect_prefix, parse_at, split_once_at, ParseError};
 
 pub struct Game {
     pub id: i32,
@@ -12,36 +12,44 @@ pub struct Round {
     pub b: i32,
 }
 
-pub fn parse_game(line: &str) -> Game {
-    let (id_str, rounds) = line.split_once(':').unwrap();
-    let id = id_str.replace("Game ", "").parse::<i32>().unwrap();
+/// Parses a `line` of the `input`, which is only there to locate errors.
+pub fn parse_game(input: &str, line: &str) -> Result<Game, ParseError> {
+    let (id_str, rounds) = split_once_at(input, line, ":")?;
+    let id = parse_at(
+        input,
+        expect_prefix(input, id_str, "Game ")?,
+        "a game number",
+    )?;
     let game = Game {
         id,
-        rounds: rounds.split(';').map(parse_round).collect_vec(),
+        rounds: rounds
+            .split(';')
+            .map(|round| parse_round(input, round))
+            .collect::<Result<_, _>>()?,
     };
-    game
+    Ok(game)
 }
 
-pub fn parse_round(line: &str) -> Round {
+pub fn parse_round(input: &str, line: &str) -> Result<Round, ParseError> {
     let tokens = line.split(',').map(|t| t.trim());
     let mut round = Round { r: 0, g: 0, b: 0 };
 
-    tokens.for_each(|t| {
-        let (val, color) = t.split_once(' ').unwrap();
-        let val = val.parse::<i32>().unwrap();
+    for t in tokens {
+        let (val, color) = split_once_at(input, t, " ")?;
+        let val = parse_at(input, val, "a number of cubes")?;
         match color {
             "red" => round.r = val,
             "green" => round.g = val,
             "blue" => round.b = val,
-            _ => panic!("Invalid color"),
+            _ => return Err(ParseError::at(input, color, "red, green or blue")),
         }
-    });
+    }
 
-    round
+    Ok(round)
 }
 
-pub fn parse_input(input: &str) -> Vec<Game> {
-    input.lines().map(parse_game).collect_vec()
+pub fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    input.lines().map(|line| parse_game(input, line)).collect()
 }
 
 pub fn is_game_possibile(condition: Round, game: &Game) -> bool {
@@ -51,8 +59,7 @@ pub fn is_game_possibile(condition: Round, game: &Game) -> bool {
         .any(|round| (round.r > condition.r) || (round.g > condition.g) || (round.b > condition.b))
 }
 
-pub fn sum_possible_ids(input: &str) -> i32 {
-    let games = parse_input(input);
+pub fn sum_possible_ids(games: &[Game]) -> i32 {
     let condition = Round {
         r: 12,
         g: 13,
@@ -66,14 +73,3 @@ pub fn sum_possible_ids(input: &str) -> i32 {
 
     possible_ids_sum
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let test = include_str!("../test.txt");
-        assert_eq!(sum_possible_ids(test), 8);
-    }
-}
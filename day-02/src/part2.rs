@@ -1,4 +1,4 @@
-use crate::part1::{parse_input, Game};
+use crate::part1::Game;
 
 pub fn min_cubes_power(game: &Game) -> i32 {
     let min_r = game.rounds.iter().map(|r| r.r).max().unwrap();
@@ -7,19 +7,7 @@ pub fn min_cubes_power(game: &Game) -> i32 {
     min_r * min_g * min_b
 }
 
-pub fn count_fewest_cubes(input: &str) -> i32 {
-    let games = parse_input(input);
+pub fn count_fewest_cubes(games: &[Game]) -> i32 {
     let min_cubes_power = games.iter().map(min_cubes_power).sum();
     min_cubes_power
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(count_fewest_cubes(input), 2286);
-    }
-}
@@ -1,2 +1,27 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    // The two parts track different things for each digit
+    type Input = (part1::Map, part2::Map);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((part1::parse_map(input)?, part2::parse_map(input)?))
+    }
+
+    fn part1((engine, _): &Self::Input) -> impl Into<Answer> {
+        part1::sum_valid_part_numbers(engine)
+    }
+
+    fn part2((_, engine): &Self::Input) -> impl Into<Answer> {
+        part2::find_gears(engine)
+    }
+}
+
+runner::example_tests!(Day03);
@@ -1,7 +1,10 @@
 use itertools::Itertools;
-use toolkit::map::{Map as BaseMap, Pos, TileDisplay};
+use toolkit::{
+    map::{Map as BaseMap, Pos, TileDisplay},
+    parse::ParseError,
+};
 
-type Map = BaseMap<Tile>;
+pub type Map = BaseMap<Tile>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Tile {
@@ -10,19 +13,20 @@ pub enum Tile {
     Symbol(char),
 }
 
-pub fn parse_map(input: &str) -> Map {
-    Map::parse(input, |c, _, _| match c {
-        '.' => None,
-        c if c.is_ascii_digit() => Some(Tile::Digit {
-            digit: c.to_digit(10).unwrap(),
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    Map::try_parse(input, |c, _, _| match (c, c.to_digit(10)) {
+        ('.', _) => Ok(None),
+        (_, Some(digit)) => Ok(Some(Tile::Digit {
+            digit,
             include: false,
-        }),
-        c => Some(Tile::Symbol(c)),
+        })),
+        (c, None) if c.is_ascii_punctuation() => Ok(Some(Tile::Symbol(c))),
+        _ => Err("a digit, '.' or a symbol"),
     })
 }
 
-pub fn sum_valid_part_numbers(input: &str) -> u32 {
-    let mut engine = parse_map(input);
+pub fn sum_valid_part_numbers(engine: &Map) -> u32 {
+    let mut engine = engine.clone();
     let engine_check = engine.clone();
 
     engine.tiles.iter_mut().for_each(|(pos, tile)| {
@@ -76,14 +80,3 @@ pub fn sum_valid_part_numbers(input: &str) -> u32 {
 
     valid_numbers.iter().sum()
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let test = include_str!("../test.txt");
-        assert_eq!(sum_valid_part_numbers(test), 4361);
-    }
-}
@@ -1,7 +1,11 @@
 use itertools::Itertools;
-use toolkit::map::{Map as BaseMap, Pos};
+use toolkit::{
+    map::{Map as BaseMap, Pos},
+    parse::ParseError,
+    trace,
+};
 
-type Map = BaseMap<Tile>;
+pub type Map = BaseMap<Tile>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Tile {
@@ -11,15 +15,16 @@ pub enum Tile {
     Symbol(char),
 }
 
-pub fn parse_map(input: &str) -> Map {
-    Map::parse(input, |c, _, _| match c {
-        '.' => None,
-        c if c.is_ascii_digit() => Some(Tile::Digit {
-            digit: c.to_digit(10).unwrap(),
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    Map::try_parse(input, |c, _, _| match (c, c.to_digit(10)) {
+        ('.', _) => Ok(None),
+        (_, Some(digit)) => Ok(Some(Tile::Digit {
+            digit,
             head: Pos::ZERO,
-        }),
-        '*' => Some(Tile::Gear),
-        c => Some(Tile::Symbol(c)),
+        })),
+        ('*', _) => Ok(Some(Tile::Gear)),
+        (c, None) if c.is_ascii_punctuation() => Ok(Some(Tile::Symbol(c))),
+        _ => Err("a digit, '.' or a symbol"),
     })
 }
 
@@ -43,8 +48,8 @@ pub fn parse_number_heads(engine: &mut Map) {
     });
 }
 
-pub fn find_gears(input: &str) -> u32 {
-    let mut engine = parse_map(input);
+pub fn find_gears(engine: &Map) -> u32 {
+    let mut engine = engine.clone();
 
     parse_number_heads(&mut engine);
 
@@ -94,7 +99,7 @@ pub fn find_gears(input: &str) -> u32 {
                 })
                 .collect_tuple()
             {
-                println!("{} * {} = {}", first, second, first * second);
+                trace!("{} * {} = {}", first, second, first * second);
                 first * second
             } else {
                 0
@@ -102,14 +107,3 @@ pub fn find_gears(input: &str) -> u32 {
         })
         .sum()
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let test = include_str!("../test.txt");
-        assert_eq!(find_gears(test), 467835);
-    }
-}
@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<part1::Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_cards(input)
+    }
+
+    fn part1(cards: &Self::Input) -> impl Into<Answer> {
+        part1::count_winning(cards)
+    }
+
+    fn part2(cards: &Self::Input) -> impl Into<Answer> {
+        part2::run_scratchcard_game(cards)
+    }
+}
+
+runner::example_tests!(Day04);
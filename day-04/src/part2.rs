@@ -1,4 +1,6 @@
-use crate::part1::{parse_cards, Card};
+use toolkit::trace;
+
+use crate::part1::Card;
 
 pub fn count_winning_numbers(card: &Card) -> u32 {
     card.numbers
@@ -7,13 +9,12 @@ pub fn count_winning_numbers(card: &Card) -> u32 {
         .count() as u32
 }
 
-pub fn run_scratchcard_game(input: &str) -> u32 {
-    let cards = parse_cards(input);
+pub fn run_scratchcard_game(cards: &[Card]) -> u32 {
     let mut copies_count: Vec<u32> = vec![1; cards.len()];
     for i in 0..cards.len() {
         let multiplier = copies_count[i];
         let score = count_winning_numbers(&cards[i]);
-        println!(
+        trace!(
             "Card: {}, Multiplier: {}, Score: {}",
             i + 1,
             multiplier,
@@ -27,14 +28,3 @@ pub fn run_scratchcard_game(input: &str) -> u32 {
 
     copies_count.iter().sum()
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let test = include_str!("../test.txt");
-        assert_eq!(run_scratchcard_game(test), 30);
-    }
-}
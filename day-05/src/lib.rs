@@ -1,2 +1,30 @@
+use runner::{Answer, Generator, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = part1::Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_almanac(input)
+    }
+
+    fn part1(almanac: &Self::Input) -> impl Into<Answer> {
+        part1::find_nearest_location(almanac)
+    }
+
+    fn part2(almanac: &Self::Input) -> impl Into<Answer> {
+        part2::find_ranges_nearest_location(almanac)
+    }
+
+    fn generate(gen: &mut Generator) -> Option<String> {
+        Some(part1::generate_almanac(gen))
+    }
+}
+
+runner::example_tests!(Day05);
@@ -1,6 +1,11 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
+use runner::Generator;
+use toolkit::{
+    debug,
+    parse::{expect_prefix, parse_at, split_once_at, ParseError},
+};
 
 pub fn part1() {}
 
@@ -54,14 +59,13 @@ impl Almanac {
     }
 }
 
-pub fn parse_almanac(input: &str) -> Almanac {
-    let chunks: Vec<_> = input.split("\n\n").collect();
-    println!("Chunks: {:?}", chunks[0]);
-    let seeds = chunks[0]
-        .replace("seeds: ", "")
+pub fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    let chunks: Vec<_> = input.trim_end().split("\n\n").collect();
+    debug!("Chunks: {:?}", chunks[0]);
+    let seeds = expect_prefix(input, chunks[0], "seeds: ")?
         .split(' ')
-        .map(|s| s.parse::<u64>().unwrap())
-        .collect::<Vec<_>>();
+        .map(|s| parse_at(input, s, "a seed number"))
+        .collect::<Result<Vec<_>, _>>()?;
 
     let mappings: Vec<Mapping> = chunks
         .iter()
@@ -70,38 +74,76 @@ pub fn parse_almanac(input: &str) -> Almanac {
             let mut lines = chunk.lines();
 
             // es. "seed-to-soil map:""
-            let key_tokens: Vec<_> = lines
-                .next()
-                .unwrap()
-                .replace(" map:", "")
-                .split('-')
-                .map(|s| s.trim().to_string())
-                .collect();
+            let header = lines.next().unwrap_or(chunk);
+            let (name, _) = split_once_at(input, header, " map:")?;
+            let (from, to) = split_once_at(input, name, "-to-")?;
 
             let rules = lines
                 .map(|line| {
-                    let tokens: Vec<_> = line.split(' ').collect();
-                    let from = tokens[0].parse::<u64>().unwrap();
-                    let to = tokens[1].parse::<u64>().unwrap();
-                    let length = tokens[2].parse::<u64>().unwrap();
-
-                    MappingRule {
-                        destination: from,
-                        source: to,
-                        length,
-                    }
+                    let Some((from, to, length)) = line.split(' ').collect_tuple() else {
+                        return Err(ParseError::at(input, line, "three numbers"));
+                    };
+
+                    Ok(MappingRule {
+                        destination: parse_at(input, from, "a number")?,
+                        source: parse_at(input, to, "a number")?,
+                        length: parse_at(input, length, "a number")?,
+                    })
                 })
-                .collect_vec();
+                .collect::<Result<Vec<_>, _>>()?;
 
-            Mapping {
-                from: key_tokens[0].clone(),
-                to: key_tokens[2].clone(),
+            Ok(Mapping {
+                from: from.trim().to_string(),
+                to: to.trim().to_string(),
                 rules,
-            }
+            })
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
+
+    Ok(Almanac { seeds, mappings })
+}
 
-    Almanac { seeds, mappings }
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// An almanac over the numbers up to `gen.size()`: five seed ranges, and maps
+/// that cut the numbers in slices and lay them out in another order.
+pub fn generate_almanac(gen: &mut Generator) -> String {
+    let size = gen.size().max(2) as u64;
+    let seeds = (0..5)
+        .flat_map(|_| {
+            let start = gen.range(0..size);
+            [start, gen.range(1..size - start + 1)]
+        })
+        .join(" ");
+
+    let mut almanac = format!("seeds: {}\n", seeds);
+    for (from, to) in CATEGORIES.iter().tuple_windows() {
+        let cuts = (0..gen.range(1..10))
+            .map(|_| gen.range(1..size))
+            .chain([0, size])
+            .sorted()
+            .dedup()
+            .collect_vec();
+        let mut slices = cuts.into_iter().tuple_windows().collect_vec();
+        gen.shuffle(&mut slices);
+
+        almanac += &format!("\n{}-to-{} map:\n", from, to);
+        let mut destination = 0;
+        for (start, end) in slices {
+            almanac += &format!("{} {} {}\n", destination, start, end - start);
+            destination += end - start;
+        }
+    }
+    almanac
 }
 
 pub fn lookup_location(almanac: &Almanac, seed: u64) -> u64 {
@@ -115,12 +157,11 @@ pub fn lookup_location(almanac: &Almanac, seed: u64) -> u64 {
     current
 }
 
-pub fn find_nearest_location(input: &str) -> u64 {
-    let almanac = parse_almanac(input);
+pub fn find_nearest_location(almanac: &Almanac) -> u64 {
     let nearest_location = almanac
         .seeds
         .iter()
-        .map(|seed| lookup_location(&almanac, *seed))
+        .map(|seed| lookup_location(almanac, *seed))
         .min()
         .unwrap();
     nearest_location
@@ -133,7 +174,7 @@ pub mod tests {
     #[test]
     fn test_parse() {
         let test = include_str!("../test.txt");
-        let almanac = parse_almanac(test);
+        let almanac = parse_almanac(test).unwrap();
         assert_eq!(almanac.seeds.len(), 4);
         assert_eq!(almanac.mappings.len(), 7);
         assert_eq!(almanac.from_mapping("humidity").to, "location");
@@ -146,8 +187,11 @@ pub mod tests {
     }
 
     #[test]
-    fn test_example() {
-        let test = include_str!("../test.txt");
-        assert_eq!(find_nearest_location(test), 35);
+    fn test_generate() {
+        let input = generate_almanac(&mut Generator::new(5, 1000));
+        let almanac = parse_almanac(&input).unwrap();
+        assert_eq!(almanac.seeds.len(), 10);
+        assert_eq!(almanac.mappings.len(), 7);
+        assert!(find_nearest_location(&almanac) <= 1000);
     }
 }
@@ -1,15 +1,14 @@
 use itertools::Itertools;
+use toolkit::debug;
 
-use crate::part1::{parse_almanac, Almanac};
+use crate::part1::Almanac;
 
 struct SeedsRange {
     seed: u64,
     range: u64,
 }
 
-pub fn find_ranges_nearest_location(input: &str) -> u64 {
-    let almanac = parse_almanac(input);
-
+pub fn find_ranges_nearest_location(almanac: &Almanac) -> u64 {
     let mut seed_ranges: Vec<SeedsRange> = vec![];
     let mut seeds_iter = almanac.seeds.iter();
     while let Some((seed, range)) = seeds_iter.next_tuple() {
@@ -22,17 +21,21 @@ pub fn find_ranges_nearest_location(input: &str) -> u64 {
     // Ok, we could theoretically do a binary search here, dividing the range for
     // each seed in multiple ranges based on the N+1 almanac mapping rules;
     // this partial bruteforce however takes just 30sec to run, so no need to bother! 🎄
+    let cancel = runner::cancel_token();
     for i in 0..4_000_000_000 {
+        if cancel.is_cancelled() {
+            return 0;
+        }
         // if i % 100_000_000 == 0 {
         //     println!("Checking location: {}", i);
         // }
-        let seed = inverse_lookup_seed(&almanac, i);
+        let seed = inverse_lookup_seed(almanac, i);
         let is_in_range = seed_ranges.iter().any(|seed_range| {
             seed_range.seed <= seed && seed_range.seed + seed_range.range >= seed
         });
 
         if is_in_range {
-            println!("Found at location: {}, rule:", i);
+            debug!("Found at location: {}", i);
             return i;
         }
     }
@@ -71,15 +74,3 @@ pub fn inverse_lookup_seed(almanac: &Almanac, location: u64) -> u64 {
     }
     current
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        println!("Test example");
-        let test = include_str!("../test.txt");
-        assert_eq!(find_ranges_nearest_location(test), 46);
-    }
-}
@@ -1,2 +1,27 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    // Part 2 reads the same sheet as a single race, ignoring the spaces
+    type Input = (Vec<part1::Race>, part1::Race);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((part1::parse_races(input)?, part2::parse_single_race(input)?))
+    }
+
+    fn part1((races, _): &Self::Input) -> impl Into<Answer> {
+        part1::multiply_winning_ways(races)
+    }
+
+    fn part2((_, race): &Self::Input) -> impl Into<Answer> {
+        part2::single_race_ways(race)
+    }
+}
+
+runner::example_tests!(Day06);
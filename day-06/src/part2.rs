@@ -1,44 +1,25 @@
 use itertools::Itertools;
 
-use crate::part1::{number_of_winning_ways, Race};
+use toolkit::parse::ParseError;
 
-pub fn single_race_ways(input: &str) -> u64 {
-    let race = parse_single_race(input);
-    number_of_winning_ways(&race)
-}
-
-pub fn parse_single_race(input: &str) -> Race {
-    let mut lines = input.lines();
-    let time = lines
-        .next()
-        .unwrap()
-        .replace("Time:", "")
-        .trim()
-        .split_ascii_whitespace()
-        .join("")
-        .parse::<u64>()
-        .unwrap();
+use crate::part1::{number_of_winning_ways, strip_label, Race};
 
-    let distance = lines
-        .next()
-        .unwrap()
-        .replace("Distance:", "")
-        .trim()
-        .split_ascii_whitespace()
-        .join("")
-        .parse::<u64>()
-        .unwrap();
-
-    Race { time, distance }
+pub fn single_race_ways(race: &Race) -> u64 {
+    number_of_winning_ways(race)
 }
 
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
+pub fn parse_single_race(input: &str) -> Result<Race, ParseError> {
+    let mut lines = input.lines();
+    let mut number = |label, expected| {
+        let numbers = strip_label(input, lines.next(), label)?;
+        numbers
+            .split_ascii_whitespace()
+            .join("")
+            .parse::<u64>()
+            .map_err(|_| ParseError::at(input, numbers, expected))
+    };
+    let time = number("Time:", "a time")?;
+    let distance = number("Distance:", "a distance")?;
 
-    #[test]
-    fn test_example() {
-        let test = include_str!("../test.txt");
-        assert_eq!(single_race_ways(test), 71503);
-    }
+    Ok(Race { time, distance })
 }
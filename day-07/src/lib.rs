@@ -1,2 +1,27 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    // In part 2 `J` is a joker, so the cards are valued differently
+    type Input = (Vec<part1::Hand>, Vec<part1::Hand>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((part1::parse_hands(input)?, part2::parse_hands(input)?))
+    }
+
+    fn part1((hands, _): &Self::Input) -> impl Into<Answer> {
+        part1::total_winnings(hands)
+    }
+
+    fn part2((_, hands): &Self::Input) -> impl Into<Answer> {
+        part2::jolly_total_winnings(hands)
+    }
+}
+
+runner::example_tests!(Day07);
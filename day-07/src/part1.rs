@@ -1,15 +1,20 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
+use toolkit::{
+    debug,
+    parse::{parse_at, split_once_at, ParseError},
+    trace,
+};
 
-pub fn parse_card(c: char) -> u32 {
+pub fn parse_card(c: char) -> Option<u32> {
     match c {
-        'A' => 14,
-        'K' => 13,
-        'Q' => 12,
-        'J' => 11,
-        'T' => 10,
-        n => n.to_digit(10).unwrap(),
+        'A' => Some(14),
+        'K' => Some(13),
+        'Q' => Some(12),
+        'J' => Some(11),
+        'T' => Some(10),
+        n => n.to_digit(10),
     }
 }
 
@@ -71,9 +76,9 @@ impl PartialEq for Hand {
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         let self_score = self.score();
-        println!("Self {} score: {}", self.to_string(), self_score);
+        trace!("Self {} score: {}", self.to_string(), self_score);
         let other_score = other.score();
-        println!("Other {} score: {}", other.to_string(), other_score);
+        trace!("Other {} score: {}", other.to_string(), other_score);
 
         match self_score.cmp(&other_score) {
             std::cmp::Ordering::Equal => {
@@ -95,39 +100,33 @@ impl Ord for Hand {
     }
 }
 
-pub fn parse_hands(input: &str) -> Vec<Hand> {
+pub fn parse_hands(input: &str) -> Result<Vec<Hand>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (cards_str, bid_str) = line.split_once(' ').unwrap();
-            let cards = cards_str.chars().map(parse_card).collect_vec();
-            let bid = bid_str.parse::<u32>().unwrap();
-            Hand { cards, bid }
+            let (cards_str, bid_str) = split_once_at(input, line, " ")?;
+            let cards = cards_str
+                .char_indices()
+                .map(|(i, c)| {
+                    let card = &cards_str[i..i + c.len_utf8()];
+                    parse_card(c).ok_or_else(|| ParseError::at(input, card, "a card"))
+                })
+                .collect::<Result<_, _>>()?;
+            let bid = parse_at(input, bid_str, "a bid")?;
+            Ok(Hand { cards, bid })
         })
-        .collect_vec()
+        .collect()
 }
 
-pub fn total_winnings(input: &str) -> u32 {
-    let hands = parse_hands(input);
-    println!("Hands: {:?}", hands);
+pub fn total_winnings(hands: &[Hand]) -> u32 {
+    debug!("Hands: {:?}", hands);
     let sorted_hands = hands.iter().sorted().collect_vec();
     sorted_hands
         .iter()
-        .for_each(|h| println!("{}", h.to_string()));
+        .for_each(|h| debug!("{}", h.to_string()));
     sorted_hands
         .iter()
         .enumerate()
         .map(|(i, hand)| hand.bid * (i as u32 + 1))
         .sum()
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(total_winnings(input), 6440);
-    }
-}
@@ -1,17 +1,22 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
+use toolkit::{
+    debug,
+    parse::{parse_at, split_once_at, ParseError},
+    trace,
+};
 
 use crate::part1::Hand;
 
-pub fn parse_card(c: char) -> u32 {
+pub fn parse_card(c: char) -> Option<u32> {
     match c {
-        'A' => 14,
-        'K' => 13,
-        'Q' => 12,
-        'J' => 1,
-        'T' => 10,
-        n => n.to_digit(10).unwrap(),
+        'A' => Some(14),
+        'K' => Some(13),
+        'Q' => Some(12),
+        'J' => Some(1),
+        'T' => Some(10),
+        n => n.to_digit(10),
     }
 }
 
@@ -28,17 +33,17 @@ pub fn format_card(c: &u32) -> char {
 
 impl Hand {
     fn jolly_score(&self) -> u32 {
-        println!("\n[Jolly] Set {}", self.to_string());
+        trace!("[Jolly] Set {}", self.to_string());
         let (jollys, cards): (Vec<_>, Vec<_>) =
             self.cards.clone().into_iter().partition(|c| *c == 1);
 
         if jollys.is_empty() {
-            println!(" - No jollys, score={}", self.score());
+            trace!(" - No jollys, score={}", self.score());
             return self.score();
         }
 
         if jollys.len() == 5 {
-            println!(" - All jollys, score=6");
+            trace!(" - All jollys, score=6");
             return 6;
         }
 
@@ -56,7 +61,7 @@ impl Hand {
                     cards: cards.clone(),
                 };
                 jolly_hand.cards.append(&mut jolly_cards);
-                println!(
+                trace!(
                     " - Try with {:?}: {}, score={}",
                     jolly_cards,
                     jolly_hand.to_string(),
@@ -69,16 +74,22 @@ impl Hand {
     }
 }
 
-pub fn parse_hands(input: &str) -> Vec<Hand> {
+pub fn parse_hands(input: &str) -> Result<Vec<Hand>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (cards_str, bid_str) = line.split_once(' ').unwrap();
-            let cards = cards_str.chars().map(parse_card).collect_vec();
-            let bid = bid_str.parse::<u32>().unwrap();
-            Hand { cards, bid }
+            let (cards_str, bid_str) = split_once_at(input, line, " ")?;
+            let cards = cards_str
+                .char_indices()
+                .map(|(i, c)| {
+                    let card = &cards_str[i..i + c.len_utf8()];
+                    parse_card(c).ok_or_else(|| ParseError::at(input, card, "a card"))
+                })
+                .collect::<Result<_, _>>()?;
+            let bid = parse_at(input, bid_str, "a bid")?;
+            Ok(Hand { cards, bid })
         })
-        .collect_vec()
+        .collect()
 }
 
 fn jolly_cmp(hand: &Hand, other: &Hand) -> std::cmp::Ordering {
@@ -100,14 +111,13 @@ fn jolly_cmp(hand: &Hand, other: &Hand) -> std::cmp::Ordering {
     }
 }
 
-pub fn jolly_total_winnings(input: &str) -> u32 {
-    let hands = parse_hands(input);
+pub fn jolly_total_winnings(hands: &[Hand]) -> u32 {
     // println!("Hands: {:?}", hands);
     let sorted_hands = hands.iter().sorted_by(|a, b| jolly_cmp(a, b)).collect_vec();
     sorted_hands
         .iter()
         .enumerate()
-        .for_each(|(i, h)| println!("{}: {}", i + 1, h.to_string()));
+        .for_each(|(i, h)| debug!("{}: {}", i + 1, h.to_string()));
     sorted_hands
         .iter()
         .enumerate()
@@ -121,15 +131,9 @@ pub mod tests {
 
     #[test]
     fn test_score() {
-        let hands = parse_hands("T55J5 1\nKTJJT 1\nQQQJA 1");
+        let hands = parse_hands("T55J5 1\nKTJJT 1\nQQQJA 1").unwrap();
         assert_eq!(hands[0].jolly_score(), 5);
         assert_eq!(hands[1].jolly_score(), 5);
         assert_eq!(hands[2].jolly_score(), 5);
     }
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(jolly_total_winnings(input), 5905);
-    }
 }
@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = (Vec<part1::Instruction>, part1::Graph);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_map(input)
+    }
+
+    fn part1((instructions, graph): &Self::Input) -> impl Into<Answer> {
+        part1::count_steps_exit(instructions, graph)
+    }
+
+    fn part2((instructions, graph): &Self::Input) -> impl Into<Answer> {
+        part2::run_ghosts_paths(instructions, graph)
+    }
+}
+
+runner::example_tests!(Day08);
@@ -1,9 +1,12 @@
-use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::{collections::HashMap, str::FromStr};
 
-use toolkit::key::Key;
+use toolkit::{
+    key::Key,
+    parse::{parse_at, split_once_at, ParseError},
+    trace,
+};
 
 pub fn part1() {}
 
@@ -25,51 +28,54 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    pub fn parse(c: char) -> Instruction {
+    pub fn parse(c: char) -> Option<Instruction> {
         match c {
-            'L' => Instruction::Left,
-            'R' => Instruction::Right,
-            _ => panic!("Invalid instruction"),
+            'L' => Some(Instruction::Left),
+            'R' => Some(Instruction::Right),
+            _ => None,
         }
     }
 }
 
-pub fn parse_map(input: &str) -> (Vec<Instruction>, Graph) {
+pub fn parse_map(input: &str) -> Result<(Vec<Instruction>, Graph), ParseError> {
     lazy_static! {
-        static ref NODE_RE: Regex = Regex::new(r"(\w+) = \((\w+), (\w+)\)").unwrap();
+        static ref NODE_RE: Regex = Regex::new(r"^(\w+) = \((\w+), (\w+)\)$").unwrap();
     }
 
-    let (instructions_data, graph_nodes) = input.split_once("\n\n").unwrap();
+    let (instructions_data, graph_nodes) = split_once_at(input, input, "\n\n")?;
 
     let instructions = instructions_data
-        .chars()
-        .map(Instruction::parse)
-        .collect_vec();
+        .char_indices()
+        .map(|(i, c)| {
+            Instruction::parse(c).ok_or_else(|| {
+                let found = &instructions_data[i..i + c.len_utf8()];
+                ParseError::at(input, found, "'L' or 'R'")
+            })
+        })
+        .collect::<Result<_, _>>()?;
 
     let mut nodes = HashMap::new();
     for line in graph_nodes.lines() {
-        let captures = NODE_RE.captures(line).unwrap();
-        // println!("Captures: {:?}", captures);
-        let key = captures[1].parse().unwrap();
-        let left = captures[2].parse().unwrap();
-        let right = captures[3].parse().unwrap();
+        let captures = NODE_RE
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "a node like \"AAA = (BBB, CCC)\""))?;
+        let key = |i| parse_at(input, &captures[i], "a node name");
+        let (key, left, right) = (key(1)?, key(2)?, key(3)?);
 
         let node = Node { key, left, right };
         nodes.insert(key, node);
     }
 
-    (instructions, Graph { nodes })
+    Ok((instructions, Graph { nodes }))
 }
 
-pub fn count_steps_exit(input: &str) -> u32 {
-    let (instructions, graph) = parse_map(input);
-
+pub fn count_steps_exit(instructions: &[Instruction], graph: &Graph) -> u32 {
     let mut current_node = graph.nodes[&Key::from_str("AAA").unwrap()];
     let mut steps = 0;
 
     loop {
         for instruction in instructions.iter() {
-            println!("Instruction: {:?}", instruction);
+            trace!("Instruction: {:?}", instruction);
             match instruction {
                 Instruction::Left => current_node = graph.nodes[&current_node.left],
                 Instruction::Right => current_node = graph.nodes[&current_node.right],
@@ -82,20 +88,3 @@ pub fn count_steps_exit(input: &str) -> u32 {
         }
     }
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test1.txt");
-        assert_eq!(count_steps_exit(input), 2);
-    }
-
-    #[test]
-    fn test_example2() {
-        let input = include_str!("../test2.txt");
-        assert_eq!(count_steps_exit(input), 6);
-    }
-}
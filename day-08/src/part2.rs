@@ -3,11 +3,9 @@ use std::collections::HashSet;
 use itertools::Itertools;
 use num::integer;
 
-use crate::part1::{parse_map, Instruction};
-
-pub fn run_ghosts_paths(input: &str) -> u64 {
-    let (instructions, graph) = parse_map(input);
+use crate::part1::{Graph, Instruction};
 
+pub fn run_ghosts_paths(instructions: &[Instruction], graph: &Graph) -> u64 {
     let current_nodes: Vec<_> = graph
         .nodes
         .keys()
@@ -44,14 +42,3 @@ pub fn run_ghosts_paths(input: &str) -> u64 {
     let lcm: u64 = patterns.iter().fold(1, |acc, x| integer::lcm(acc, *x));
     lcm
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test3.txt");
-        assert_eq!(run_ghosts_paths(input), 6);
-    }
-}
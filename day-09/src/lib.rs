@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<part1::Sequence>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse(input)
+    }
+
+    fn part1(seqs: &Self::Input) -> impl Into<Answer> {
+        part1::sum_predictions(seqs)
+    }
+
+    fn part2(seqs: &Self::Input) -> impl Into<Answer> {
+        part2::sum_postdictions(seqs)
+    }
+}
+
+runner::example_tests!(Day09);
@@ -1,12 +1,17 @@
+use toolkit::{
+    parse::{parse_at, ParseError},
+    trace,
+};
+
 pub type Sequence = Vec<i32>;
 
-pub fn parse(input: &str) -> Vec<Sequence> {
+pub fn parse(input: &str) -> Result<Vec<Sequence>, ParseError> {
     input
         .lines()
         .map(|line| {
             line.split_ascii_whitespace()
-                .map(|x| x.parse::<i32>().unwrap())
-                .collect::<Vec<_>>()
+                .map(|x| parse_at(input, x, "a number"))
+                .collect()
         })
         .collect()
 }
@@ -24,7 +29,7 @@ pub fn is_zero_delta(seq: &Sequence) -> bool {
 }
 
 pub fn predict(seq: &Sequence) -> i32 {
-    println!("Predict {:?}", seq);
+    trace!("Predict {:?}", seq);
     let mut deltas: Vec<Sequence> = vec![seq.clone()];
     loop {
         let delta = compute_deltas(deltas.last().unwrap().clone());
@@ -42,8 +47,7 @@ pub fn predict(seq: &Sequence) -> i32 {
     prediction
 }
 
-pub fn sum_predictions(input: &str) -> i32 {
-    let seqs = parse(input);
+pub fn sum_predictions(seqs: &[Sequence]) -> i32 {
     seqs.iter().map(predict).sum()
 }
 
@@ -54,13 +58,7 @@ pub mod tests {
     #[test]
     fn test_predict() {
         let input = "0   3   6   9  12  15";
-        let seq = parse(input);
+        let seq = parse(input).unwrap();
         assert_eq!(predict(seq.last().unwrap()), 18);
     }
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(sum_predictions(input), 114);
-    }
 }
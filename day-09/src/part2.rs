@@ -1,7 +1,9 @@
-use crate::part1::{compute_deltas, is_zero_delta, parse, Sequence};
+use toolkit::trace;
+
+use crate::part1::{compute_deltas, is_zero_delta, Sequence};
 
 pub fn postdict(seq: &Sequence) -> i32 {
-    println!("Postdict {:?}", seq);
+    trace!("Postdict {:?}", seq);
     let mut deltas: Vec<Sequence> = vec![seq.clone()];
     loop {
         let delta = compute_deltas(deltas.last().unwrap().clone());
@@ -19,18 +21,6 @@ pub fn postdict(seq: &Sequence) -> i32 {
     postdiction
 }
 
-pub fn sum_postdictions(input: &str) -> i32 {
-    let seqs = parse(input);
+pub fn sum_postdictions(seqs: &[Sequence]) -> i32 {
     seqs.iter().map(postdict).sum()
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(sum_postdictions(input), 2);
-    }
-}
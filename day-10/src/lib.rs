@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = part1::Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_map(input)
+    }
+
+    fn part1(map: &Self::Input) -> impl Into<Answer> {
+        part1::farthest_pipe(map)
+    }
+
+    fn part2(map: &Self::Input) -> impl Into<Answer> {
+        part2::count_inside_loop(map)
+    }
+}
+
+runner::example_tests!(Day10);
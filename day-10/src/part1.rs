@@ -2,7 +2,13 @@ use std::{collections::HashMap, fmt::Display};
 
 use colored::Colorize;
 use itertools::Itertools;
-use toolkit::map::{Map as BaseMap, Pos, TileDisplay};
+use toolkit::{
+    log::Level,
+    log_enabled,
+    map::{Map as BaseMap, Pos, TileDisplay},
+    parse::ParseError,
+    trace,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Tile {
@@ -77,19 +83,19 @@ pub fn matching_pipe(pos: Pos) -> Pos {
     }
 }
 
-pub fn parse_map(input: &str) -> Map {
-    Map::parse(input, |c, x, y| {
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    Map::try_parse(input, |c, x, y| {
         let pos = Pos::new(x, y);
         match c {
-            '.' => Some(Tile::Ground),
-            'S' => Some(Tile::Start),
-            '|' => Some(tile_pipe(pos, Pos::UP, Pos::DOWN)),
-            '-' => Some(tile_pipe(pos, Pos::LEFT, Pos::RIGHT)),
-            'L' => Some(tile_pipe(pos, Pos::UP, Pos::RIGHT)),
-            'J' => Some(tile_pipe(pos, Pos::UP, Pos::LEFT)),
-            'F' => Some(tile_pipe(pos, Pos::DOWN, Pos::RIGHT)),
-            '7' => Some(tile_pipe(pos, Pos::DOWN, Pos::LEFT)),
-            _ => panic!("Invalid character: {}", c),
+            '.' => Ok(Some(Tile::Ground)),
+            'S' => Ok(Some(Tile::Start)),
+            '|' => Ok(Some(tile_pipe(pos, Pos::UP, Pos::DOWN))),
+            '-' => Ok(Some(tile_pipe(pos, Pos::LEFT, Pos::RIGHT))),
+            'L' => Ok(Some(tile_pipe(pos, Pos::UP, Pos::RIGHT))),
+            'J' => Ok(Some(tile_pipe(pos, Pos::UP, Pos::LEFT))),
+            'F' => Ok(Some(tile_pipe(pos, Pos::DOWN, Pos::RIGHT))),
+            '7' => Ok(Some(tile_pipe(pos, Pos::DOWN, Pos::LEFT))),
+            _ => Err("a pipe, '.' or 'S'"),
         }
     })
 }
@@ -108,15 +114,17 @@ pub fn get_starting_pipes(map: &Map, start_pos: Pos) -> ((Pos, Pipe), (Pos, Pipe
         .iter()
         .filter_map(|(pos, tile)| {
             let target_pipe = matching_pipe(*pos - start_pos);
-            println!(
+            trace!(
                 "target_pipe: {:?}, pos={:?}, start={:?}",
-                target_pipe, pos, start_pos
+                target_pipe,
+                pos,
+                start_pos
             );
             match tile {
                 Tile::Pipe(pipe)
                     if pipe.start - *pos == target_pipe || pipe.end - *pos == target_pipe =>
                 {
-                    println!(" - found pipe: {:?}", pipe);
+                    trace!(" - found pipe: {:?}", pipe);
                     Some((*pos, *pipe))
                 }
                 _ => None,
@@ -128,15 +136,16 @@ pub fn get_starting_pipes(map: &Map, start_pos: Pos) -> ((Pos, Pipe), (Pos, Pipe
     (*start_cw, *start_ccw)
 }
 
-pub fn farthest_pipe(input: &str) -> u32 {
-    let map = parse_map(input);
-    let start_pos = get_start_pos(&map);
+pub fn farthest_pipe(map: &Map) -> u32 {
+    let start_pos = get_start_pos(map);
 
     let distance;
 
-    map.print();
+    if log_enabled!(Level::Debug) {
+        map.print();
+    }
 
-    let (start_cw, start_ccw) = get_starting_pipes(&map, start_pos);
+    let (start_cw, start_ccw) = get_starting_pipes(map, start_pos);
 
     // We iterate clockwise and counter-clockwise at the same time, tracking
     // the distance from the start position to each tile. When the two
@@ -166,7 +175,7 @@ pub fn farthest_pipe(input: &str) -> u32 {
                 let cw_distance = tracked_clockwise.get(&current_cw).unwrap_or(&0) + 1;
                 tracked_clockwise.insert(*cw_target, cw_distance);
                 current_cw = *cw_target;
-                println!("cw distance: {}", cw_distance);
+                trace!("cw distance: {}", cw_distance);
             }
             _ => panic!("Invalid tile: {:?}", cw_tile),
         }
@@ -183,7 +192,7 @@ pub fn farthest_pipe(input: &str) -> u32 {
                 let ccw_distance = tracked_counter_clockwise.get(&current_ccw).unwrap_or(&0) + 1;
                 tracked_counter_clockwise.insert(*ccw_target, ccw_distance);
                 current_ccw = *ccw_target;
-                println!("ccw distance: {}", ccw_distance);
+                trace!("ccw distance: {}", ccw_distance);
             }
             _ => panic!("Invalid tile: {:?}", ccw_tile),
         }
@@ -195,14 +204,3 @@ pub fn farthest_pipe(input: &str) -> u32 {
     }
     distance
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(farthest_pipe(input), 8);
-    }
-}
@@ -2,9 +2,13 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use colored::Colorize;
 use itertools::Itertools;
-use toolkit::map::{Pos, TileDisplay};
+use toolkit::{
+    log::Level,
+    log_enabled,
+    map::{Pos, TileDisplay},
+};
 
-use crate::part1::{get_start_pos, get_starting_pipes, matching_pipe, parse_map, Map, Pipe, Tile};
+use crate::part1::{get_start_pos, get_starting_pipes, matching_pipe, Map, Pipe, Tile};
 
 fn resolve_starting_pipe(map: &mut Map, start_pos: Pos, pipe_start: Pos, pipe_end: Pos) {
     map.tiles.entry(start_pos).and_modify(|start_tile| {
@@ -16,8 +20,8 @@ fn resolve_starting_pipe(map: &mut Map, start_pos: Pos, pipe_start: Pos, pipe_en
     });
 }
 
-pub fn count_inside_loop(input: &str) -> u32 {
-    let mut map = parse_map(input);
+pub fn count_inside_loop(map: &Map) -> u32 {
+    let mut map = map.clone();
     let start_pos = get_start_pos(&map);
     let (start_cw, start_ccw) = get_starting_pipes(&map, start_pos);
 
@@ -26,7 +30,9 @@ pub fn count_inside_loop(input: &str) -> u32 {
 
     let mut pipe_loop: HashSet<Pos> = HashSet::new();
 
-    map.print();
+    if log_enabled!(Level::Debug) {
+        map.print();
+    }
 
     let mut current = start_pos;
     loop {
@@ -173,30 +179,5 @@ pub fn count_inside_loop(input: &str) -> u32 {
         }
     });
 
-    // 1218 too high
-    // 498 too high
     inside_list.len() as u32
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test2.txt");
-        assert_eq!(count_inside_loop(input), 4);
-    }
-
-    #[test]
-    fn test_example_10() {
-        let input = include_str!("../test3.txt");
-        assert_eq!(count_inside_loop(input), 10);
-    }
-
-    #[test]
-    fn test_example_4() {
-        let input = include_str!("../test4.txt");
-        assert_eq!(count_inside_loop(input), 8);
-    }
-}
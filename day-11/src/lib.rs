@@ -1,2 +1,37 @@
+use runner::{Answer, Params, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = part1::Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_universe(input)
+    }
+
+    fn part1(universe: &Self::Input) -> impl Into<Answer> {
+        part1::count_distances(universe)
+    }
+
+    fn part2(universe: &Self::Input) -> impl Into<Answer> {
+        part2::count_old_distances(universe, 1_000_000)
+    }
+
+    fn example(part: u8, universe: &Self::Input, params: &Params) -> Answer {
+        match part {
+            1 => part1::count_distances(universe).into(),
+            // Examples without an `expansion` are checked as the real input
+            _ => {
+                let expansion = params.get("expansion").unwrap_or(1_000_000);
+                part2::count_old_distances(universe, expansion).into()
+            }
+        }
+    }
+}
+
+runner::example_tests!(Day11);
@@ -1,11 +1,12 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
 use itertools::Itertools;
-use toolkit::{graph::bfs_cache, map::Pos};
+use toolkit::{debug, graph::bfs_cache, log::Level, log_enabled, map::Pos};
 
-use crate::part1::{expand_universe, parse_universe, Map, Tile};
+use crate::part1::{expand_universe, Map, Tile};
 
 pub fn universe_bfs_cache(map: &Map, pos: Pos, expansion: u64) -> HashMap<Pos, u64> {
+    let _span = runner::span("bfs");
     let mut queue = VecDeque::new();
     let mut visited = HashSet::new();
     let mut cache = HashMap::new();
@@ -43,10 +44,11 @@ pub fn universe_bfs_cache(map: &Map, pos: Pos, expansion: u64) -> HashMap<Pos, u
     cache
 }
 
-pub fn count_old_distances(input: &str, expansion: u64) -> u64 {
-    let map = parse_universe(input);
-    println!("Expanded map:");
-    map.print();
+pub fn count_old_distances(map: &Map, expansion: u64) -> u64 {
+    if log_enabled!(Level::Debug) {
+        debug!("Expanded map:");
+        map.print();
+    }
     let galaxies = map
         .tiles
         .iter()
@@ -58,11 +60,12 @@ pub fn count_old_distances(input: &str, expansion: u64) -> u64 {
 
     let distance_cache: HashMap<Pos, HashMap<Pos, u64>> = galaxies
         .iter()
-        .map(|&(pos, galaxy_index)| (pos, universe_bfs_cache(&map, pos, expansion)))
+        .map(|&(pos, galaxy_index)| (pos, universe_bfs_cache(map, pos, expansion)))
         .collect();
 
+    let _span = runner::span("sum pairs");
     let pairs = galaxies.iter().combinations(2).collect_vec();
-    println!("Pairs: {}", pairs.len());
+    debug!("Pairs: {}", pairs.len());
     pairs
         .iter()
         .map(|pair| {
@@ -70,19 +73,7 @@ pub fn count_old_distances(input: &str, expansion: u64) -> u64 {
                 .get(&pair[0].0)
                 .unwrap()
                 .get(&pair[1].0)
-                .unwrap() as u64
+                .unwrap()
         })
         .sum()
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(count_old_distances(input, 10), 1030);
-        assert_eq!(count_old_distances(input, 100), 8410);
-    }
-}
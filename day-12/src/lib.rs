@@ -1,2 +1,46 @@
+use runner::{Alternative, Answer, Generator, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<part1::Row>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_springs(input)
+    }
+
+    fn part1(rows: &Self::Input) -> impl Into<Answer> {
+        part1::solve_valid_springs(rows)
+    }
+
+    fn part2(rows: &Self::Input) -> impl Into<Answer> {
+        part2::solve_folded_springs(rows)
+    }
+
+    fn generate(gen: &mut Generator) -> Option<String> {
+        Some(part1::generate_springs(gen))
+    }
+
+    // Unfolded rows are far too long to backtrack, so part 2 has none
+    fn alternatives() -> Vec<Alternative<Self::Input>> {
+        vec![
+            Alternative {
+                part: 1,
+                name: "brute force",
+                solve: |rows| rows.iter().map(part1::solve_row).sum::<u32>().into(),
+            },
+            Alternative {
+                part: 1,
+                name: "cached",
+                solve: |rows| rows.iter().map(part2::solve_cached).sum::<u64>().into(),
+            },
+        ]
+    }
+}
+
+runner::example_tests!(Day12);
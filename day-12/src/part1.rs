@@ -1,6 +1,11 @@
 use std::fmt::Display;
 
 use itertools::Itertools;
+use runner::Generator;
+use toolkit::{
+    parse::{parse_at, split_once_at, ParseError},
+    trace,
+};
 
 use crate::part2::solve_backtracking;
 
@@ -29,7 +34,7 @@ pub struct Row {
     pub fold_groups_len: usize,
 }
 
-pub fn parse_springs(input: &str) -> Vec<Row> {
+pub fn parse_springs(input: &str) -> Result<Vec<Row>, ParseError> {
     input
         .lines()
         .map(|line| {
@@ -40,28 +45,60 @@ pub fn parse_springs(input: &str) -> Vec<Row> {
                 fold_groups_len: 0,
             };
 
-            let (springs, groups) = line.split_once(' ').unwrap();
+            let (springs, groups) = split_once_at(input, line, " ")?;
 
-            for c in springs.chars() {
+            for (i, c) in springs.char_indices() {
                 match c {
                     '#' => row.springs.push(Spring::Broken),
                     '.' => row.springs.push(Spring::Operative),
                     '?' => row.springs.push(Spring::Unknown),
-                    _ => (),
+                    _ => {
+                        let spring = &springs[i..i + c.len_utf8()];
+                        return Err(ParseError::at(input, spring, "'#', '.' or '?'"));
+                    }
                 }
             }
 
             for group in groups.split(',') {
-                let group = group.parse::<usize>().unwrap();
-                row.groups.push(group);
+                row.groups.push(parse_at(input, group, "a group size")?);
             }
 
             row.fold_len = row.springs.len();
             row.fold_groups_len = row.groups.len();
 
-            row
+            Ok(row)
         })
-        .collect_vec()
+        .collect()
+}
+
+/// `gen.size()` rows of 5 to 20 springs, about a third of them unknown.
+pub fn generate_springs(gen: &mut Generator) -> String {
+    (0..gen.size())
+        .map(|_| {
+            let len = gen.range(5..21) as usize;
+            let mut broken = (0..len).map(|_| gen.chance(0.5)).collect_vec();
+            if !broken.contains(&true) {
+                broken[gen.range(0..len as u64) as usize] = true;
+            }
+
+            let groups = broken
+                .iter()
+                .group_by(|&&broken| broken)
+                .into_iter()
+                .filter(|(broken, _)| *broken)
+                .map(|(_, group)| group.count())
+                .join(",");
+            let springs = broken
+                .iter()
+                .map(|&broken| match (gen.chance(0.35), broken) {
+                    (true, _) => '?',
+                    (false, true) => '#',
+                    (false, false) => '.',
+                })
+                .collect::<String>();
+            format!("{} {}\n", springs, groups)
+        })
+        .collect()
 }
 
 fn springs_match_group(springs: &Vec<Spring>, groups: &Vec<usize>) -> bool {
@@ -101,13 +138,17 @@ pub fn solve_row(row: &Row) -> u32 {
         .enumerate()
         .filter(|(_, s)| matches!(s, Spring::Unknown))
         .collect_vec();
+    // The product of no iterators is empty, not a single empty combination
+    if unknows.is_empty() {
+        return springs_match_group(&row.springs, &row.groups) as u32;
+    }
 
     let valid_spring_types = vec!['.', '#'];
     let combinations = (1..=unknows.len())
         .map(|_| valid_spring_types.iter())
         .multi_cartesian_product();
 
-    println!("Combs for row: {}", row.springs.iter().join(""));
+    trace!("Combs for row: {}", row.springs.iter().join(""));
 
     for combination in combinations {
         let mut springs = row.springs.clone();
@@ -131,8 +172,7 @@ pub fn solve_row(row: &Row) -> u32 {
     valid_count
 }
 
-pub fn solve_valid_springs(input: &str) -> u64 {
-    let rows = parse_springs(input);
+pub fn solve_valid_springs(rows: &[Row]) -> u64 {
     rows.iter().map(solve_backtracking).sum()
 }
 
@@ -141,8 +181,21 @@ pub mod tests {
     use crate::part1::*;
 
     #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(solve_valid_springs(input), 21);
+    fn test_generate() {
+        let input = generate_springs(&mut Generator::new(12, 50));
+        assert_eq!(input, generate_springs(&mut Generator::new(12, 50)));
+        // The springs the row was drawn from are always an arrangement
+        let rows = parse_springs(&input).unwrap();
+        assert_eq!(rows.len(), 50);
+        assert!(rows.iter().all(|row| solve_backtracking(row) >= 1));
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let day = runner::Day::new::<crate::Day12>();
+        for seed in 0..10 {
+            let input = generate_springs(&mut Generator::new(seed, 20));
+            assert_eq!(runner::compare(&day, 1, &input), None);
+        }
     }
 }
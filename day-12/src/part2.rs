@@ -244,7 +244,7 @@ pub fn solve_partial(row: &PartialRow) -> u64 {
     result
 }
 
-fn solve_cached(row: &Row) -> u64 {
+pub fn solve_cached(row: &Row) -> u64 {
     debug!(
         "\n\n{} Solving: {}, groups: {:?}",
         "[BEGIN]".cyan(),
@@ -260,22 +260,18 @@ fn solve_cached(row: &Row) -> u64 {
 }
 
 pub fn solve_cached_str(row: &str) -> u64 {
-    let row = &parse_springs(row)[0];
+    let row = &parse_springs(row).unwrap()[0];
     solve_cached(row)
 }
 
 pub fn solve_backtracking_str(row: &str) -> u64 {
-    let row = &parse_springs(row)[0];
+    let row = &parse_springs(row).unwrap()[0];
     solve_backtracking(row) as u64
 }
 
-pub fn solve_folded_springs(input: &str) -> u64 {
-    let rows = parse_springs(input)
-        .iter()
-        .map(|r| r.unfold())
-        .collect_vec();
+pub fn solve_folded_springs(rows: &[Row]) -> u64 {
+    let rows = rows.iter().map(|r| r.unfold()).collect_vec();
 
-    // too low 78849875086
     rows.iter().map(solve_cached).sum()
 }
 
@@ -296,53 +292,53 @@ pub mod tests {
     #[test]
     fn test_solve_simple() {
         let input = "????.######..#####. 1,6,5";
-        assert_eq!(solve_folded_springs(input), 2500);
+        assert_eq!(solve_folded_springs(&parse_springs(input).unwrap()), 2500);
         let input = "???.### 1,1,3";
-        assert_eq!(solve_folded_springs(input), 1);
+        assert_eq!(solve_folded_springs(&parse_springs(input).unwrap()), 1);
     }
 
     #[test]
     fn test_solve_sample1() {
         let input = "?###???????? 3,2,1";
-        assert_eq!(solve_folded_springs(input), 506250);
+        assert_eq!(solve_folded_springs(&parse_springs(input).unwrap()), 506250);
     }
 
     #[test]
     fn test_final_only_one() {
-        let row = &parse_springs("?## 3")[0];
+        let row = &parse_springs("?## 3").unwrap()[0];
         assert_eq!(solve_cached(row), 1);
-        let row = &parse_springs("?##. 3")[0];
+        let row = &parse_springs("?##. 3").unwrap()[0];
         assert_eq!(solve_cached(row), 1);
     }
 
     #[test]
     fn test_solve_folded_springs() {
         let input = ".??..??...?##. 1,1,3";
-        assert_eq!(solve_folded_springs(input), 16384);
+        assert_eq!(solve_folded_springs(&parse_springs(input).unwrap()), 16384);
         let input = "?#?#?#?#?#?#?#? 1,3,1,6";
-        assert_eq!(solve_folded_springs(input), 1);
+        assert_eq!(solve_folded_springs(&parse_springs(input).unwrap()), 1);
         let input = "????.#...#... 4,1,1";
-        assert_eq!(solve_folded_springs(input), 16);
+        assert_eq!(solve_folded_springs(&parse_springs(input).unwrap()), 16);
     }
 
     #[test]
     fn test_input_backtracking() {
         let input = ".?.??#?##??#????.. 8,1";
-        assert_eq!(solve_folded_springs(input), 55301); // 12005
+        assert_eq!(solve_folded_springs(&parse_springs(input).unwrap()), 55301); // 12005
         let input = "#?#???????#?.? 3,1,2,2";
-        assert_eq!(solve_folded_springs(input), 58564);
+        assert_eq!(solve_folded_springs(&parse_springs(input).unwrap()), 58564);
         let input = "..?????#.. 1,1";
-        assert_eq!(solve_folded_springs(input), 23184);
+        assert_eq!(solve_folded_springs(&parse_springs(input).unwrap()), 23184);
         let input = "..?????#.. 1,1";
         assert_eq!(solve_cached_str(input), solve_backtracking_str(input));
-        let row = parse_springs("..?????#.. 1,1")[0].unfold();
+        let row = parse_springs("..?????#.. 1,1").unwrap()[0].unfold();
         assert_eq!(solve_cached(&row), solve_backtracking(&row));
     }
 
     #[test]
     fn test_test_part1() {
         let input = include_str!("../test.txt");
-        let rows = parse_springs(input);
+        let rows = parse_springs(input).unwrap();
         assert_eq!(solve_cached(&rows[0]), 1);
         assert_eq!(solve_cached(&rows[1]), 4);
         assert_eq!(solve_cached(&rows[2]), 1);
@@ -353,8 +349,10 @@ pub mod tests {
 
     #[test]
     fn test_input_part1() {
-        let input = include_str!("../input.txt");
-        let rows = parse_springs(input);
+        let Some(input) = runner::local_input(env!("CARGO_MANIFEST_DIR")) else {
+            return;
+        };
+        let rows = parse_springs(&input).unwrap();
         let sum = rows.iter().map(solve_cached).sum::<u64>();
         assert_eq!(sum, 7007);
     }
@@ -423,9 +421,4 @@ pub mod tests {
     //     let row = parse_springs("..?????#.. 1,1")[0].unfold();
     //     assert_eq!(solve_backtracking(&row), 0);
     // }
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(solve_folded_springs(input), 525152);
-    }
 }
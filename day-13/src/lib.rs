@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<part1::Map>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_patterns(input)
+    }
+
+    fn part1(patterns: &Self::Input) -> impl Into<Answer> {
+        part1::find_reflections(patterns)
+    }
+
+    fn part2(patterns: &Self::Input) -> impl Into<Answer> {
+        part2::find_smudged_reflections(patterns)
+    }
+}
+
+runner::example_tests!(Day13);
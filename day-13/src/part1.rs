@@ -1,10 +1,10 @@
 use std::cmp;
 
 use colored::Colorize;
-use itertools::Itertools;
 use toolkit::{
     debug,
     map::{Map as BaseMap, Pos, TileDisplay},
+    parse::ParseError,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -24,17 +24,18 @@ impl TileDisplay for Tile {
 
 pub type Map = BaseMap<Tile>;
 
-pub fn parse_patterns(input: &str) -> Vec<Map> {
+pub fn parse_patterns(input: &str) -> Result<Vec<Map>, ParseError> {
     input
         .split("\n\n")
         .map(|pattern| {
-            Map::parse(pattern, |c, _x, _y| match c {
-                '#' => Some(Tile::Rock),
-                '.' => Some(Tile::Ash),
-                _ => panic!("Invalid tile"),
+            Map::try_parse(pattern, |c, _x, _y| match c {
+                '#' => Ok(Some(Tile::Rock)),
+                '.' => Ok(Some(Tile::Ash)),
+                _ => Err("'#' or '.'"),
             })
+            .map_err(|err| err.within(input, pattern))
         })
-        .collect_vec()
+        .collect()
 }
 
 pub fn find_reflection_columns(map: &Map) -> Vec<i32> {
@@ -84,9 +85,7 @@ pub fn find_reflection_rows(map: &Map) -> Vec<i32> {
     rows
 }
 
-pub fn find_reflections(input: &str) -> i32 {
-    let patterns = parse_patterns(input);
-
+pub fn find_reflections(patterns: &[Map]) -> i32 {
     patterns
         .iter()
         .map(|pattern| {
@@ -105,15 +104,9 @@ pub mod tests {
 
     #[test]
     fn test_reflection_columns() {
-        let patterns = parse_patterns(include_str!("../test.txt"));
+        let patterns = parse_patterns(include_str!("../test.txt")).unwrap();
         let pattern = patterns.first().unwrap();
         let cols = find_reflection_columns(pattern);
         assert_eq!(cols, vec![5]);
     }
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(find_reflections(input), 405);
-    }
 }
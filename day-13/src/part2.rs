@@ -1,6 +1,6 @@
 use toolkit::{debug, map::Pos};
 
-use crate::part1::{find_reflection_columns, find_reflection_rows, parse_patterns, Map, Tile};
+use crate::part1::{find_reflection_columns, find_reflection_rows, Map, Tile};
 
 impl Tile {
     fn swap(&self) -> Tile {
@@ -55,8 +55,7 @@ fn find_alternate_reflection_columns(pattern: &Map) -> (i32, i32) {
     panic!("No reflection found");
 }
 
-pub fn find_smudged_reflections(input: &str) -> i32 {
-    let patterns = parse_patterns(input);
+pub fn find_smudged_reflections(patterns: &[Map]) -> i32 {
     patterns
         .iter()
         .map(|pattern| {
@@ -66,14 +65,3 @@ pub fn find_smudged_reflections(input: &str) -> i32 {
         })
         .sum()
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(find_smudged_reflections(input), 400);
-    }
-}
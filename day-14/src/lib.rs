@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = part1::Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_rocks(input)
+    }
+
+    fn part1(map: &Self::Input) -> impl Into<Answer> {
+        part1::north_beams_load(map)
+    }
+
+    fn part2(map: &Self::Input) -> impl Into<Answer> {
+        part2::run_slide_cycles(map)
+    }
+}
+
+runner::example_tests!(Day14);
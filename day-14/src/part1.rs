@@ -1,7 +1,9 @@
 use colored::Colorize;
 use toolkit::{
-    debug,
+    log::Level,
+    log_enabled,
     map::{self, Map as BaseMap, Pos, TileDisplay},
+    parse::ParseError,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,12 +25,12 @@ impl TileDisplay for Tile {
 
 pub type Map = BaseMap<Tile>;
 
-pub fn parse_rocks(input: &str) -> Map {
-    Map::parse(input, |c, _, _| match c {
-        'O' => Some(Tile::Round),
-        '#' => Some(Tile::Cube),
-        '.' => Some(Tile::Empty),
-        _ => None,
+pub fn parse_rocks(input: &str) -> Result<Map, ParseError> {
+    Map::try_parse(input, |c, _, _| match c {
+        'O' => Ok(Some(Tile::Round)),
+        '#' => Ok(Some(Tile::Cube)),
+        '.' => Ok(Some(Tile::Empty)),
+        _ => Err("'O', '#' or '.'"),
     })
 }
 
@@ -87,20 +89,11 @@ pub fn calculate_north_beams_load(map: &Map) -> u32 {
         .sum::<u32>()
 }
 
-pub fn north_beams_load(input: &str) -> u32 {
-    let mut map = parse_rocks(input);
+pub fn north_beams_load(map: &Map) -> u32 {
+    let mut map = map.clone();
     slide_direction(&mut map, Direction::North);
-    map.print();
-    calculate_north_beams_load(&map)
-}
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(north_beams_load(input), 136);
+    if log_enabled!(Level::Debug) {
+        map.print();
     }
+    calculate_north_beams_load(&map)
 }
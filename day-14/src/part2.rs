@@ -1,4 +1,6 @@
-use crate::part1::{calculate_north_beams_load, parse_rocks, slide_direction, Direction, Map};
+use toolkit::{debug, log::Level, log_enabled};
+
+use crate::part1::{calculate_north_beams_load, slide_direction, Direction, Map};
 
 pub fn run_slide_cycle(map: &mut Map) {
     slide_direction(map, Direction::North);
@@ -7,29 +9,26 @@ pub fn run_slide_cycle(map: &mut Map) {
     slide_direction(map, Direction::East);
 }
 
-// too low 103856
-// too low 103860
-// too high 103876
-pub fn run_slide_cycles(input: &str) -> u32 {
-    let mut map = parse_rocks(input);
+pub fn run_slide_cycles(map: &Map) -> u32 {
+    let mut map = map.clone();
     // run_slide_cycle(&mut map);
     let mut latest = vec![];
     let mut skipped = false;
     let mut i = 0;
-    while i < 1_000_000_000 {
-        if i % 100_000 == 0 {
-            println!("Cycle {}", i);
-        }
+    let cancel = runner::cancel_token();
+    let mut progress = runner::Progress::new("cycles");
+    while i < 1_000_000_000 && !cancel.is_cancelled() {
+        progress.tick(i as u64, 1_000_000_000);
 
         run_slide_cycle(&mut map);
         // map.print();
 
         if let Some(prev_index) = latest.iter().position(|m| *m == map) {
-            println!("Cycle {} is the same as cycle {}", i, prev_index);
+            debug!("Cycle {} is the same as cycle {}", i, prev_index);
             let delta = i - prev_index;
 
             i += 1_000_000_000 - (1_000_000_000 % delta) - delta * ((i / delta) + 1);
-            println!("Skipping to cycle {}", i);
+            debug!("Skipping to cycle {}", i);
             skipped = true;
             latest.clear();
         }
@@ -39,7 +38,9 @@ pub fn run_slide_cycles(input: &str) -> u32 {
             latest.push(map.clone());
         }
     }
-    map.print();
+    if log_enabled!(Level::Debug) {
+        map.print();
+    }
     calculate_north_beams_load(&map)
 }
 
@@ -51,17 +52,11 @@ pub mod tests {
     fn test_one_cycle() {
         let input = include_str!("../test.txt");
         let expected = include_str!("../test_1cycle.txt");
-        let mut map = parse_rocks(input);
-        let expected_map = parse_rocks(expected);
+        let mut map = parse_rocks(input).unwrap();
+        let expected_map = parse_rocks(expected).unwrap();
         run_slide_cycle(&mut map);
         map.print();
         expected_map.print();
         assert_eq!(map, expected_map);
     }
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(run_slide_cycles(input), 64);
-    }
 }
@@ -1,2 +1,27 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    // The initialization sequence, with the line breaks dropped
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.replace('\n', ""))
+    }
+
+    fn part1(seq: &Self::Input) -> impl Into<Answer> {
+        part1::hash_init_seq(seq)
+    }
+
+    fn part2(seq: &Self::Input) -> impl Into<Answer> {
+        part2::focusing_power(seq)
+    }
+}
+
+runner::example_tests!(Day15);
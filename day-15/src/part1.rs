@@ -20,10 +20,4 @@ pub mod tests {
     fn test_hash() {
         assert_eq!(run_hash("HASH"), 52);
     }
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(hash_init_seq(input), 1320);
-    }
 }
@@ -77,14 +77,3 @@ pub fn focusing_power(input: &str) -> u32 {
         })
         .sum::<u32>()
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(focusing_power(input), 145);
-    }
-}
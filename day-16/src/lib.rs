@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = part1::Contraption;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_contraption(input)
+    }
+
+    fn part1(contraption: &Self::Input) -> impl Into<Answer> {
+        part1::count_energized_topleft(contraption)
+    }
+
+    fn part2(contraption: &Self::Input) -> impl Into<Answer> {
+        part2::top_energized(contraption)
+    }
+}
+
+runner::example_tests!(Day16);
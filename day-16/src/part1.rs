@@ -3,8 +3,8 @@ use std::collections::{HashSet, VecDeque};
 use colored::Colorize;
 use itertools::Itertools;
 use toolkit::{
-    debug,
     map::{Map, Pos, TileDisplay},
+    parse::ParseError,
 };
 
 pub fn part1() {}
@@ -32,20 +32,19 @@ impl TileDisplay for Tile {
 pub type Contraption = Map<Tile>;
 pub type Dir = Pos;
 
-pub fn parse_contraption(input: &str) -> Contraption {
-    Map::parse(input, |c, _x, _y| match c {
-        '\\' => Some(Tile::MirrorTopLeft),
-        '/' => Some(Tile::MirrorTopRight),
-        '|' => Some(Tile::SplitterVertical),
-        '-' => Some(Tile::SplitterHorizontal),
-        '.' => Some(Tile::Empty),
-        _ => None,
+pub fn parse_contraption(input: &str) -> Result<Contraption, ParseError> {
+    Map::try_parse(input, |c, _x, _y| match c {
+        '\\' => Ok(Some(Tile::MirrorTopLeft)),
+        '/' => Ok(Some(Tile::MirrorTopRight)),
+        '|' => Ok(Some(Tile::SplitterVertical)),
+        '-' => Ok(Some(Tile::SplitterHorizontal)),
+        '.' => Ok(Some(Tile::Empty)),
+        _ => Err("a mirror, a splitter or '.'"),
     })
 }
 
-pub fn count_energized_topleft(input: &str) -> usize {
-    let contraption = parse_contraption(input);
-    count_energized(&contraption, (Pos::new(-1, 0), Pos::RIGHT))
+pub fn count_energized_topleft(contraption: &Contraption) -> usize {
+    count_energized(contraption, (Pos::new(-1, 0), Pos::RIGHT))
 }
 
 pub fn count_energized(contraption: &Contraption, start: (Pos, Dir)) -> usize {
@@ -133,14 +132,3 @@ pub fn count_energized(contraption: &Contraption, start: (Pos, Dir)) -> usize {
     // debug!("Energized: {:#?}", energized);
     energized.iter().unique_by(|(p, _)| *p).count()
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(count_energized_topleft(input), 46);
-    }
-}
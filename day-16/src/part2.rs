@@ -1,9 +1,8 @@
 use toolkit::map::Pos;
 
-use crate::part1::{count_energized, parse_contraption};
+use crate::part1::{count_energized, Contraption};
 
-pub fn top_energized(input: &str) -> usize {
-    let contraption = parse_contraption(input);
+pub fn top_energized(contraption: &Contraption) -> usize {
     let mut top_count = 0;
 
     let mut starts = vec![];
@@ -18,7 +17,7 @@ pub fn top_energized(input: &str) -> usize {
     }
 
     for start in starts {
-        let count = count_energized(&contraption, start);
+        let count = count_energized(contraption, start);
         if count > top_count {
             top_count = count;
         }
@@ -26,14 +25,3 @@ pub fn top_energized(input: &str) -> usize {
 
     top_count
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(top_energized(input), 51);
-    }
-}
@@ -1,2 +1,27 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    // Part 2 reads the real plan out of the color codes
+    type Input = (Vec<part1::PlanItem>, Vec<part1::PlanItem>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((part1::parse_plan(input)?, part2::parse_real_plan(input)?))
+    }
+
+    fn part1((plan, _): &Self::Input) -> impl Into<Answer> {
+        part1::dig_lagoon(plan)
+    }
+
+    fn part2((_, plan): &Self::Input) -> impl Into<Answer> {
+        part2::dig_wide_lagoon(plan)
+    }
+}
+
+runner::example_tests!(Day18);
@@ -4,12 +4,14 @@ use std::{
 };
 
 use colored::Colorize;
-use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use toolkit::{
     color::Rgb,
+    log::Level,
+    log_enabled,
     map::{Map as BaseMap, Pos, TileDisplay},
+    parse::{parse_at, ParseError},
 };
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -19,29 +21,31 @@ pub struct PlanItem {
     pub color: Rgb,
 }
 
-pub fn parse_plan(input: &str) -> Vec<PlanItem> {
+pub fn parse_plan(input: &str) -> Result<Vec<PlanItem>, ParseError> {
     lazy_static! {
-        static ref ITEM_RE: Regex = Regex::new(r"(\w+) (\d+) \(#(\w+)\)").unwrap();
+        static ref ITEM_RE: Regex = Regex::new(r"^(\w+) (\d+) \(#([0-9a-f]{6})\)$").unwrap();
     }
 
     input
         .lines()
         .map(|line| {
-            let captures = ITEM_RE.captures(line).unwrap();
-            let dir = match captures.get(1).unwrap().as_str() {
+            let captures = ITEM_RE
+                .captures(line)
+                .ok_or_else(|| ParseError::at(input, line, "a step like \"R 6 (#70c710)\""))?;
+            let dir = match &captures[1] {
                 "U" => Pos::UP,
                 "D" => Pos::DOWN,
                 "R" => Pos::RIGHT,
                 "L" => Pos::LEFT,
-                _ => unreachable!(),
+                dir => return Err(ParseError::at(input, dir, "U, D, R or L")),
             };
 
-            let dist = captures.get(2).unwrap().as_str().parse::<u32>().unwrap();
-            let color = captures.get(3).unwrap().as_str().parse::<Rgb>().unwrap();
+            let dist = parse_at(input, &captures[2], "a distance")?;
+            let color = parse_at(input, &captures[3], "a color")?;
 
-            PlanItem { dir, dist, color }
+            Ok(PlanItem { dir, dist, color })
         })
-        .collect_vec()
+        .collect()
 }
 
 pub enum Tile {
@@ -62,8 +66,7 @@ impl TileDisplay for Tile {
 
 pub type Map = BaseMap<Tile>;
 
-pub fn dig_lagoon(input: &str) -> u32 {
-    let plan = parse_plan(input);
+pub fn dig_lagoon(plan: &[PlanItem]) -> u32 {
     let mut map = Map::new();
     map.set(Pos::new(0, 0), Tile::Dig { color: Rgb::WHITE });
 
@@ -76,7 +79,9 @@ pub fn dig_lagoon(input: &str) -> u32 {
     }
 
     map.update_bounds();
-    map.print();
+    if log_enabled!(Level::Debug) {
+        map.print();
+    }
 
     let mut fill_pos = Pos::new(map.bounds.min.x, 0);
     loop {
@@ -146,20 +151,11 @@ pub fn dig_lagoon(input: &str) -> u32 {
     // }
 
     map.update_bounds();
-    map.print();
+    if log_enabled!(Level::Debug) {
+        map.print();
+    }
 
     map.iter()
         .filter(|(_, tile)| !matches!(tile, Tile::Empty))
         .count() as u32
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(dig_lagoon(input), 62);
-    }
-}
@@ -10,33 +10,38 @@ use toolkit::{
     color::Rgb,
     debug,
     map::{Bounds, Pos},
+    parse::ParseError,
 };
 
-pub fn parse_real_plan(input: &str) -> Vec<PlanItem> {
+pub fn parse_real_plan(input: &str) -> Result<Vec<PlanItem>, ParseError> {
     lazy_static! {
-        static ref ITEM_RE: Regex = Regex::new(r"(\w+) (\d+) \(#(\w+)\)").unwrap();
+        static ref ITEM_RE: Regex = Regex::new(r"^(\w+) (\d+) \(#([0-9a-f]{6})\)$").unwrap();
     }
 
     input
         .lines()
         .map(|line| {
-            let captures = ITEM_RE.captures(line).unwrap();
-            let dist = u32::from_str_radix(&captures[3][0..5], 16).unwrap();
-            let dir = match u32::from_str_radix(&captures[3][5..6], 16).unwrap() {
-                0 => Pos::RIGHT,
-                1 => Pos::DOWN,
-                2 => Pos::LEFT,
-                3 => Pos::UP,
-                _ => unreachable!(),
+            let captures = ITEM_RE
+                .captures(line)
+                .ok_or_else(|| ParseError::at(input, line, "a step like \"R 6 (#70c710)\""))?;
+            let code = &captures[3];
+            let dist = u32::from_str_radix(&code[0..5], 16)
+                .map_err(|_| ParseError::at(input, &code[0..5], "a distance in hex"))?;
+            let dir = match &code[5..6] {
+                "0" => Pos::RIGHT,
+                "1" => Pos::DOWN,
+                "2" => Pos::LEFT,
+                "3" => Pos::UP,
+                dir => return Err(ParseError::at(input, dir, "a direction from 0 to 3")),
             };
 
-            PlanItem {
+            Ok(PlanItem {
                 dir,
                 dist,
                 color: Rgb::WHITE,
-            }
+            })
         })
-        .collect_vec()
+        .collect()
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -50,12 +55,11 @@ pub struct Edge {
     pub is_vertical: bool,
 }
 
-pub fn dig_wide_lagoon(input: &str) -> u64 {
-    let plan = parse_real_plan(input);
+pub fn dig_wide_lagoon(plan: &[PlanItem]) -> u64 {
     dig_lagoon_with_plan(plan)
 }
 
-pub fn dig_lagoon_with_plan(plan: Vec<PlanItem>) -> u64 {
+pub fn dig_lagoon_with_plan(plan: &[PlanItem]) -> u64 {
     let mut map = Map::new();
     map.set(Pos::new(0, 0), Tile::Dig { color: Rgb::WHITE });
 
@@ -129,7 +133,7 @@ pub fn dig_lagoon_with_plan(plan: Vec<PlanItem>) -> u64 {
         y_count += fill_count;
     }
 
-    println!("bounds: {:?}, edges: {}", bounds, edges.len());
+    debug!("bounds: {:?}, edges: {}", bounds, edges.len());
     y_count as u64
 }
 
@@ -140,14 +144,10 @@ pub mod tests {
     #[test]
     fn test_part1() {
         let input = include_str!("../test.txt");
-        assert_eq!(dig_lagoon_with_plan(parse_plan(input)), 62);
-        let input = include_str!("../input.txt");
-        assert_eq!(dig_lagoon_with_plan(parse_plan(input)), 50603);
-    }
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(dig_wide_lagoon(input), 952408144115);
+        assert_eq!(dig_lagoon_with_plan(&parse_plan(input).unwrap()), 62);
+        let Some(input) = runner::local_input(env!("CARGO_MANIFEST_DIR")) else {
+            return;
+        };
+        assert_eq!(dig_lagoon_with_plan(&parse_plan(&input).unwrap()), 50603);
     }
 }
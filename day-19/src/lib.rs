@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = (part1::WorkflowMap, Vec<part1::Part>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_workflows(input)
+    }
+
+    fn part1((workflows, parts): &Self::Input) -> impl Into<Answer> {
+        part1::count_accepted_parts(workflows, parts)
+    }
+
+    fn part2((workflows, _): &Self::Input) -> impl Into<Answer> {
+        part2::count_acceptable(workflows)
+    }
+}
+
+runner::example_tests!(Day19);
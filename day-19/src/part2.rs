@@ -3,7 +3,7 @@ use std::{cmp, fmt::Display};
 
 use toolkit::{debug, key::Key};
 
-use crate::part1::{parse_workflows, Branch, Compare, Rule, WorkflowMap};
+use crate::part1::{Branch, Compare, Rule, WorkflowMap};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Range {
@@ -39,11 +39,9 @@ impl Display for Solution {
     }
 }
 
-pub fn count_acceptable(input: &str) -> u64 {
-    let (workflow_map, _) = parse_workflows(input);
-
+pub fn count_acceptable(workflow_map: &WorkflowMap) -> u64 {
     solve_range(
-        &workflow_map,
+        workflow_map,
         Solution {
             ranges: [
                 Default::default(),
@@ -57,6 +55,7 @@ pub fn count_acceptable(input: &str) -> u64 {
 }
 
 pub fn solve_range(workflow_map: &WorkflowMap, solution: Solution, branch: Branch) -> u64 {
+    let _span = runner::span("solve_range");
     debug!("\nSolving: {} => {}", solution, branch.to_string().cyan());
     match branch {
         Branch::Accept => {
@@ -99,14 +98,3 @@ pub fn solve_range(workflow_map: &WorkflowMap, solution: Solution, branch: Branc
         }
     }
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part2::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(count_acceptable(input), 167409079868000);
-    }
-}
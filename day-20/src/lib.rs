@@ -1,2 +1,29 @@
+use std::collections::HashMap;
+
+use runner::{Answer, ParseError, Solution};
+use toolkit::key::Key;
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = HashMap<Key, part1::Module>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse_modules(input)
+    }
+
+    fn part1(modules: &Self::Input) -> impl Into<Answer> {
+        part1::run_cycles(modules, 1000)
+    }
+
+    fn part2(modules: &Self::Input) -> impl Into<Answer> {
+        part2::find_min_rx_cycles(modules)
+    }
+}
+
+runner::example_tests!(Day20);
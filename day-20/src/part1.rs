@@ -1,7 +1,12 @@
 use itertools::Itertools;
 use std::collections::{HashMap, VecDeque};
 use std::hash::{Hash, Hasher};
-use toolkit::{debug, key::Key};
+use toolkit::{
+    debug,
+    key::Key,
+    parse::{parse_at, split_once_at, ParseError},
+    trace,
+};
 
 pub enum Kind {
     Broadcaster,
@@ -16,40 +21,41 @@ pub struct Module {
     pub inputs: Vec<Key>,
 }
 
-pub fn parse_modules(input: &str) -> HashMap<Key, Module> {
+pub fn parse_modules(input: &str) -> Result<HashMap<Key, Module>, ParseError> {
     let mut hashmap: HashMap<Key, Module> = input
         .lines()
         .map(|line| {
-            let (desc, outputs) = line.split_once(" -> ").unwrap();
+            let (desc, outputs) = split_once_at(input, line, " -> ")?;
             let outputs = outputs
                 .split(", ")
-                .map(|s| s.parse::<Key>().unwrap())
-                .collect_vec();
+                .map(|s| parse_at(input, s, "a module name"))
+                .collect::<Result<Vec<Key>, _>>()?;
 
-            let module = match desc.chars().next().unwrap() {
-                '&' => Module {
-                    key: desc[1..].parse::<Key>().unwrap(),
+            let module = match desc.chars().next() {
+                Some('&') => Module {
+                    key: parse_at(input, &desc[1..], "a module name")?,
                     kind: Kind::Conjunction,
                     outputs,
                     inputs: vec![], // We'll fill this in later
                 },
-                '%' => Module {
-                    key: desc[1..].parse::<Key>().unwrap(),
+                Some('%') => Module {
+                    key: parse_at(input, &desc[1..], "a module name")?,
                     kind: Kind::FlipFlop,
                     outputs,
                     inputs: vec![],
                 },
-                _ => Module {
-                    key: desc.parse::<Key>().unwrap(),
+                Some(_) => Module {
+                    key: parse_at(input, desc, "a module name")?,
                     kind: Kind::Broadcaster,
                     outputs,
                     inputs: vec![],
                 },
+                None => return Err(ParseError::at(input, desc, "a module")),
             };
 
-            (module.key, module)
+            Ok((module.key, module))
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
     let mut inputs: HashMap<Key, Vec<Key>> = HashMap::new();
 
@@ -60,7 +66,7 @@ pub fn parse_modules(input: &str) -> HashMap<Key, Module> {
     });
 
     inputs.iter().for_each(|(key, inputs)| {
-        println!("{} -> {:?}", key, inputs);
+        trace!("{} -> {:?}", key, inputs);
         if !hashmap.contains_key(key) {
             return;
         }
@@ -71,7 +77,7 @@ pub fn parse_modules(input: &str) -> HashMap<Key, Module> {
         }
     });
 
-    hashmap
+    Ok(hashmap)
 }
 
 #[derive(Debug, Clone, Default, Eq)]
@@ -108,14 +114,14 @@ impl State {
     }
 
     pub fn print(&self) {
-        println!("\n\nFlipFlop:");
+        debug!("FlipFlop:");
         self.flipflop.iter().for_each(|(key, value)| {
-            println!("  {} = {}", key, value);
+            debug!("  {} = {}", key, value);
         });
 
-        println!("\nConjunction:");
+        debug!("Conjunction:");
         self.conjunction.iter().for_each(|(key, value)| {
-            println!(
+            debug!(
                 "  {} => {:?}",
                 key,
                 value
@@ -149,12 +155,11 @@ impl Hash for State {
     }
 }
 
-pub fn run_cycles(input: &str, count: usize) -> u64 {
-    let modules = parse_modules(input);
-    let mut state = State::from_modules(&modules);
+pub fn run_cycles(modules: &HashMap<Key, Module>, count: usize) -> u64 {
+    let mut state = State::from_modules(modules);
 
     for _ in 0..count {
-        run_cycle(&mut state, &modules);
+        run_cycle(&mut state, modules);
     }
 
     state.counts_high * state.counts_low
@@ -217,14 +222,3 @@ pub fn run_cycle(state: &mut State, modules: &HashMap<Key, Module>) {
         }
     }
 }
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(run_cycles(input, 1000), 32000000);
-    }
-}
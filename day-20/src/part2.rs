@@ -1,32 +1,32 @@
 use std::collections::HashMap;
 
-use toolkit::key::Key;
+use toolkit::{debug, key::Key, log::Level, log_enabled};
 
-use crate::part1::{parse_modules, run_cycle, Module, State};
+use crate::part1::{run_cycle, Module, State};
 
-pub fn find_min_rx_cycles(input: &str) -> u64 {
-    let modules = parse_modules(input);
-    let mut state = State::from_modules(&modules);
+pub fn find_min_rx_cycles(modules: &HashMap<Key, Module>) -> u64 {
+    let mut state = State::from_modules(modules);
     let original_state = state.clone();
-    original_state.print();
+    if log_enabled!(Level::Debug) {
+        original_state.print();
+    }
 
     let mut hashes: HashMap<State, u64> = HashMap::new();
     hashes.insert(state.clone(), 0);
 
     let mut i = 0;
-    loop {
-        if i % 100_000 == 0 {
-            println!("i: {}", i);
-            state.print();
-        }
+    let cancel = runner::cancel_token();
+    let mut progress = runner::Progress::new("presses");
+    while !cancel.is_cancelled() {
+        progress.tick(i, None);
         if state.rx_received_low {
-            println!("RX received low at {}", i);
+            debug!("RX received low at {}", i);
             break;
         }
 
-        run_cycle(&mut state, &modules);
+        run_cycle(&mut state, modules);
         if let Some(prev) = hashes.get(&state) {
-            println!("Cycle detected at {}, prev={}", i, prev);
+            debug!("Cycle detected at {}, prev={}", i, prev);
             break;
         }
 
@@ -54,14 +54,16 @@ pub mod tests {
     use std::collections::{HashSet, VecDeque};
 
     use itertools::Itertools;
-    use toolkit::key;
+    use toolkit::{debug, key};
 
-    use crate::part2::*;
+    use crate::{part1::parse_modules, part2::*};
 
     #[test]
     fn print_tree() {
-        let input = include_str!("../input.txt");
-        let modules = parse_modules(input);
+        let Some(input) = runner::local_input(env!("CARGO_MANIFEST_DIR")) else {
+            return;
+        };
+        let modules = parse_modules(&input).unwrap();
         let mut state = State::from_modules(&modules);
         let inputs = get_inputs(&modules);
         let mut queue: VecDeque<(u32, Vec<Key>)> = VecDeque::new();
@@ -72,14 +74,14 @@ pub mod tests {
         while let Some((indent, mods)) = queue.pop_front() {
             for key in mods {
                 if visited.contains(&key) {
-                    println!("{}{}: {}", " ".repeat(indent as usize), key, "(visited)");
+                    debug!("{}{}: {}", " ".repeat(indent as usize), key, "(visited)");
                     continue;
                 }
                 visited.insert(key);
                 let button_default_input = vec![];
                 let mod_inputs = inputs.get(&key).unwrap_or(&button_default_input);
                 let mod_str = mod_inputs.iter().map(|m| m.to_string()).join(", ");
-                println!("{}{}: {}", " ".repeat(indent as usize), key, mod_str);
+                debug!("{}{}: {}", " ".repeat(indent as usize), key, mod_str);
                 if mod_inputs.is_empty() {
                     continue;
                 }
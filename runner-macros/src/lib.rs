@@ -0,0 +1,96 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+use proc_macro::TokenStream;
+use toml::{Table, Value};
+
+/// Emits one test per answer listed in the calling crate's `examples.toml`,
+/// named `example_<n>_part<p>` after the entry and the part it checks:
+///
+/// ```ignore
+/// runner::example_tests!(Day11);
+/// ```
+///
+/// The manifest and the inputs are included in the tests, so editing them
+/// rebuilds the crate.
+#[proc_macro]
+pub fn example_tests(solution: TokenStream) -> TokenStream {
+    let solution = solution.to_string();
+    let dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest = Path::new(&dir).join("examples.toml");
+
+    match tests(&solution, &manifest) {
+        Ok(tests) => tests,
+        Err(err) => format!("compile_error!({:?});", err),
+    }
+    .parse()
+    .unwrap()
+}
+
+fn tests(solution: &str, manifest: &Path) -> Result<String, String> {
+    let content = fs::read_to_string(manifest)
+        .map_err(|err| format!("cannot read {}: {}", manifest.display(), err))?;
+    let table = content
+        .parse::<Table>()
+        .map_err(|err| format!("{}: {}", manifest.display(), err))?;
+    let examples = match table.get("example") {
+        Some(Value::Array(examples)) => examples.as_slice(),
+        Some(_) => {
+            return Err(format!(
+                "{}: `example` must be an array",
+                manifest.display()
+            ))
+        }
+        None => &[],
+    };
+
+    let mut tests = String::new();
+    writeln!(tests, "#[cfg(test)] mod examples {{ use super::*;").unwrap();
+    writeln!(tests, "const _: &str = include_str!({:?});", manifest).unwrap();
+    for (i, example) in examples.iter().enumerate() {
+        let invalid = |what: &str| format!("{}: example {} {}", manifest.display(), i + 1, what);
+        let example = example
+            .as_table()
+            .ok_or_else(|| invalid("is not a table"))?;
+        let input = example
+            .get("input")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid("has no `input`"))?;
+        let input = manifest.with_file_name(input);
+        let params = match example.get("params") {
+            Some(Value::Table(params)) => params
+                .iter()
+                .map(|(name, value)| format!("({:?}, {:?}),", name, text(value)))
+                .collect::<String>(),
+            Some(_) => return Err(invalid("has `params` that are not a table")),
+            None => String::new(),
+        };
+
+        for part in [1, 2] {
+            let Some(expected) = example.get(&format!("part{}", part)) else {
+                continue;
+            };
+            writeln!(
+                tests,
+                "#[test] fn example_{}_part{}() {{ ::runner::check_example::<{}>(include_str!({:?}), {}, {:?}, &[{}]); }}",
+                i + 1,
+                part,
+                solution,
+                input,
+                part,
+                text(expected),
+                params
+            )
+            .unwrap();
+        }
+    }
+    tests.push('}');
+    Ok(tests)
+}
+
+/// Values are compared as text, whether they were written as strings or not.
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    ops::AddAssign,
+    ptr,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering::Relaxed},
+};
+
+use crate::CancelToken;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// The flag of the token set with [`CountUntil`], null if there is none.
+    /// Const and without a destructor, so the allocator can read it.
+    static STOP: Cell<*const AtomicBool> = const { Cell::new(ptr::null()) };
+}
+
+/// The system allocator, counting what goes through it. Installed as the
+/// global allocator by the `alloc-stats` feature, as counting slows down the
+/// days that allocate a lot.
+pub struct CountingAlloc;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+impl CountingAlloc {
+    fn allocated(size: usize) {
+        let live = LIVE.fetch_add(size as u64, Relaxed) + size as u64;
+        if counted() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            ALLOCATED.fetch_add(size as u64, Relaxed);
+            PEAK.fetch_max(live, Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size() as u64, Relaxed);
+    }
+
+    /// Counted as freeing the old block and allocating the new one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            LIVE.fetch_sub(layout.size() as u64, Relaxed);
+            Self::allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// What was allocated while some code ran.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Most bytes live at once, on top of what was live when it started
+    pub peak: u64,
+}
+
+impl AddAssign for AllocStats {
+    fn add_assign(&mut self, other: Self) {
+        self.allocations += other.allocations;
+        self.bytes += other.bytes;
+        self.peak = self.peak.max(other.peak);
+    }
+}
+
+/// Whether the counting allocator is installed.
+pub fn alloc_stats_enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Stops counting the allocations of this thread once `token` is cancelled,
+/// until it is dropped. The meters assume that spans close in order, which a
+/// part left running after its timeout no longer does: it would add to the
+/// spans of whatever runs next.
+pub(crate) struct CountUntil {
+    /// Keeps the flag [`STOP`] points to alive
+    _token: CancelToken,
+}
+
+impl CountUntil {
+    pub(crate) fn new(token: CancelToken) -> Self {
+        STOP.set(token.flag());
+        Self { _token: token }
+    }
+}
+
+impl Drop for CountUntil {
+    fn drop(&mut self) {
+        STOP.set(ptr::null());
+    }
+}
+
+/// Whether the allocations of this thread are counted, see [`CountUntil`].
+fn counted() -> bool {
+    let stop = STOP.try_with(Cell::get).unwrap_or(ptr::null());
+    // Only set while the `CountUntil` holding the flag is alive on this thread
+    stop.is_null() || !unsafe { &*stop }.load(Relaxed)
+}
+
+/// Counts the allocations from its start to [`AllocMeter::stop`]. Meters
+/// must be stopped in the reverse order they were started, like spans.
+pub(crate) struct AllocMeter {
+    allocations: u64,
+    bytes: u64,
+    live: u64,
+    /// The peak of the enclosing meter, to restore once this one stops
+    outer_peak: u64,
+}
+
+impl AllocMeter {
+    pub(crate) fn start() -> Self {
+        let live = LIVE.load(Relaxed);
+        Self {
+            allocations: ALLOCATIONS.load(Relaxed),
+            bytes: ALLOCATED.load(Relaxed),
+            live,
+            outer_peak: PEAK.swap(live, Relaxed),
+        }
+    }
+
+    pub(crate) fn stop(self) -> AllocStats {
+        // The peak now belongs to the meters of another run
+        if !counted() {
+            return AllocStats::default();
+        }
+        let peak = PEAK.fetch_max(self.outer_peak, Relaxed);
+        AllocStats {
+            allocations: ALLOCATIONS.load(Relaxed) - self.allocations,
+            bytes: ALLOCATED.load(Relaxed) - self.bytes,
+            peak: peak.saturating_sub(self.live),
+        }
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{}B", bytes),
+        _ => format!("{:.1}{}", value, UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meter() {
+        let layout = |size| Layout::from_size_align(size, 8).unwrap();
+        let outer = AllocMeter::start();
+        unsafe {
+            let a = CountingAlloc.alloc(layout(1000));
+            let inner = AllocMeter::start();
+            let b = CountingAlloc.alloc(layout(300));
+            let b = CountingAlloc.realloc(b, layout(300), 500);
+            CountingAlloc.dealloc(b, layout(500));
+            let inner = inner.stop();
+            CountingAlloc.dealloc(a, layout(1000));
+
+            // Anything else allocating at the same time only adds up
+            assert!(inner.allocations >= 2);
+            assert!(inner.bytes >= 800);
+            assert!(inner.peak >= 500);
+        }
+        let outer = outer.stop();
+        assert!(outer.allocations >= 3);
+        assert!(outer.bytes >= 1800);
+        assert!(outer.peak >= 1500);
+    }
+
+    #[test]
+    fn test_count_until() {
+        let token = CancelToken::new();
+        let worker_token = token.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let worker = std::thread::spawn(move || {
+            let before = counted();
+            let counting = CountUntil::new(worker_token);
+            rx.recv().unwrap();
+            let cancelled = counted();
+            drop(counting);
+            (before, cancelled, counted())
+        });
+
+        assert!(counted());
+        token.cancel();
+        tx.send(()).unwrap();
+        assert_eq!(worker.join().unwrap(), (true, false, true));
+        assert!(counted());
+    }
+
+    #[test]
+    fn test_add() {
+        let mut stats = AllocStats {
+            allocations: 2,
+            bytes: 100,
+            peak: 80,
+        };
+        stats += AllocStats {
+            allocations: 1,
+            bytes: 50,
+            peak: 50,
+        };
+        assert_eq!(
+            stats,
+            AllocStats {
+                allocations: 3,
+                bytes: 150,
+                peak: 80,
+            }
+        );
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0B");
+        assert_eq!(format_bytes(1023), "1023B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0MiB");
+    }
+}
@@ -0,0 +1,214 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+use toolkit::{map::read_letters, warn};
+
+/// What a part returns, whatever its integer type, as text, or as a grid of
+/// lines for puzzles that draw their answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// Without trailing whitespace nor blank lines around, see
+    /// [`Answer::lines`]
+    Lines(Vec<String>),
+    /// The part is not solved yet
+    Unsolved,
+}
+
+impl Answer {
+    /// A multi-line answer, such as a rendered grid. Trailing whitespace and
+    /// the blank lines before and after the grid are dropped.
+    pub fn lines<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> Self {
+        let lines = lines
+            .into_iter()
+            .map(|line| line.as_ref().trim_end().to_string())
+            .collect::<Vec<_>>();
+        let start = lines.iter().position(|l| !l.is_empty()).unwrap_or(0);
+        let end = lines
+            .iter()
+            .rposition(|l| !l.is_empty())
+            .map_or(0, |i| i + 1);
+        Answer::Lines(lines[start..end.max(start)].to_vec())
+    }
+
+    /// The letters drawn by the lit `pixels`, as text. If they cannot be read,
+    /// the reason is logged and the answer is the drawing itself, so it can
+    /// still be read by eye.
+    pub fn letters(pixels: &[Vec<bool>]) -> Self {
+        match read_letters(pixels) {
+            Ok(letters) => Answer::Text(letters),
+            Err(err) => {
+                warn!("Cannot read the letters: {}", err);
+                Answer::from(pixels.to_vec())
+            }
+        }
+    }
+
+    /// Whether this is the `expected` answer, as written in an answers or
+    /// examples file. Numbers compare by value, and the whitespace around
+    /// text and lines does not matter.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Number(n) => expected.trim().parse::<i128>() == Ok(*n),
+            Answer::Text(text) => text.trim() == expected.trim(),
+            Answer::Lines(_) => *self == Answer::lines(expected.lines()),
+            Answer::Unsolved => false,
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Lines(lines) if lines.len() > 1)
+    }
+
+    /// The answer on a single line, for tables.
+    pub fn inline(&self) -> String {
+        match self {
+            Answer::Lines(lines) => lines.join("\\n"),
+            answer => answer.to_string(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => n.fmt(f),
+            Answer::Text(text) => f.pad(text),
+            Answer::Lines(lines) => f.write_str(&lines.join("\n")),
+            Answer::Unsolved => f.pad("-"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty)*) => {$(
+        impl From<$int> for Answer {
+            fn from(n: $int) -> Self {
+                Answer::Number(n as i128)
+            }
+        }
+    )*};
+}
+
+from_int!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 usize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+/// Text spanning several lines is taken as a grid.
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        match text.trim().contains('\n') {
+            true => Answer::lines(text.lines()),
+            false => Answer::Text(text),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::from(text.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Text(c.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Self {
+        Answer::lines(lines)
+    }
+}
+
+impl From<Vec<Vec<char>>> for Answer {
+    fn from(grid: Vec<Vec<char>>) -> Self {
+        Answer::lines(grid.iter().map(|row| row.iter().collect::<String>()))
+    }
+}
+
+/// Lit pixels are drawn as `#`, the others as `.`.
+impl From<Vec<Vec<bool>>> for Answer {
+    fn from(pixels: Vec<Vec<bool>>) -> Self {
+        Answer::lines(pixels.iter().map(|row| {
+            row.iter()
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect::<String>()
+        }))
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::Unsolved
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(42u8), Answer::Number(42));
+        assert_eq!(Answer::from(-7i32), Answer::Number(-7));
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("ABC"), Answer::Text("ABC".to_string()));
+        assert_eq!(Answer::from(()), Answer::Unsolved);
+        assert_eq!(Answer::from(None::<u32>), Answer::Unsolved);
+        assert_eq!(
+            Answer::from(vec![vec![true, false], vec![false, true]]),
+            Answer::Lines(vec!["#.".to_string(), ".#".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(Answer::from(7007u64).matches("7007\n"));
+        assert!(Answer::from(7007u64).matches(" 07007"));
+        assert!(!Answer::from(7007u64).matches("7008"));
+        assert!(Answer::from("ABC").matches("ABC "));
+        assert!(!Answer::Unsolved.matches("-"));
+
+        let grid = Answer::from("\n#..#  \n####\n\n".to_string());
+        assert!(grid.is_multiline());
+        assert!(grid.matches("#..#\n####"));
+        assert!(!grid.matches("#..#\n###."));
+    }
+
+    #[test]
+    fn test_letters() {
+        let pixels = ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .to_vec();
+        assert_eq!(Answer::letters(&pixels), Answer::Text("H".to_string()));
+
+        let pixels = pixels[..5].to_vec();
+        assert_eq!(Answer::letters(&pixels), Answer::from(pixels));
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Answer::lines(["#.", ".#"]);
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!(grid.inline(), "#.\\n.#");
+        assert_eq!(format!("{:>4}", Answer::from(42)), "  42");
+        assert_eq!(Answer::Unsolved.to_string(), "-");
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, PartError};
+
+/// Known answers for a day's real input, stored as `day-x/answers.toml`,
+/// along with the guesses that turned out wrong:
+///
+/// ```toml
+/// part1 = 7007
+///
+/// [[wrong]]
+/// part = 2
+/// answer = "1218"
+/// hint = "too high"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "answer")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "answer")]
+    pub part2: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<WrongAnswer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WrongAnswer {
+    pub part: u8,
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// What the website said about a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Hint {
+    #[serde(rename = "too high")]
+    TooHigh,
+    #[serde(rename = "too low")]
+    TooLow,
+}
+
+impl Answers {
+    /// The answers file of an input other than the day's own, next to it:
+    /// `alice.answers.toml` for `alice.txt`.
+    pub fn path_for(input: impl AsRef<Path>) -> PathBuf {
+        input.as_ref().with_extension("answers.toml")
+    }
+
+    /// Reads the answers file. A missing file means no answer is known yet.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+
+        toml::from_str(&content).map_err(|err| {
+            let msg = format!("{}: {}", path.display(), err);
+            io::Error::new(io::ErrorKind::InvalidData, msg)
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let content = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(path, content)
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        let answer = Some(answer.trim().to_string());
+        match part {
+            1 => self.part1 = answer,
+            2 => self.part2 = answer,
+            _ => panic!("Invalid part: {}", part),
+        }
+    }
+
+    pub fn add_wrong(&mut self, part: u8, answer: &str, hint: Option<Hint>) {
+        self.wrong.push(WrongAnswer {
+            part,
+            answer: answer.trim().to_string(),
+            hint,
+        });
+    }
+
+    /// Explains why `answer` is known to be wrong, either because it was
+    /// already rejected or because it is out of the bounds given by previous
+    /// "too high" and "too low" hints.
+    pub fn known_wrong(&self, part: u8, answer: &str) -> Option<String> {
+        let answer = answer.trim();
+        let value = answer.parse::<i128>().ok();
+
+        self.wrong.iter().filter(|w| w.part == part).find_map(|w| {
+            let bound = w.answer.parse::<i128>().ok();
+            match (w.hint, value.zip(bound)) {
+                _ if w.answer == answer => Some(format!("{} was already rejected", answer)),
+                (Some(Hint::TooHigh), Some((value, bound))) if value > bound => {
+                    Some(format!("{} was too high already", bound))
+                }
+                (Some(Hint::TooLow), Some((value, bound))) if value < bound => {
+                    Some(format!("{} was too low already", bound))
+                }
+                _ => None,
+            }
+        })
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => panic!("Invalid part: {}", part),
+        }
+    }
+
+    pub fn verify(&self, part: u8, actual: &Answer) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Missing,
+            Some(expected) if actual.matches(expected) => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+        }
+    }
+
+    /// Like [`Answers::verify`], for a part that may not have answered: a
+    /// timeout has its own verdict, and a panic has none.
+    pub fn check(&self, part: u8, result: &Result<Answer, PartError>) -> Option<Verdict> {
+        match result {
+            Ok(actual) => Some(self.verify(part, actual)),
+            Err(PartError::TimedOut(_)) => Some(Verdict::Timeout),
+            Err(PartError::Panicked(_)) => None,
+        }
+    }
+}
+
+/// Outcome of checking an answer against the known one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+    /// The part did not finish in time, so there is nothing to check
+    Timeout,
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "FAIL",
+            Verdict::Missing => "missing",
+            Verdict::Timeout => "TIMEOUT",
+        };
+        f.pad(s)
+    }
+}
+
+/// Answers may be written either as TOML integers or strings, but are always
+/// compared as text.
+pub(crate) mod answer {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Int(i64),
+        Str(String),
+    }
+
+    pub fn serialize<S: Serializer>(value: &Option<String>, s: S) -> Result<S::Ok, S::Error> {
+        match value.as_deref().map(|v| (v, v.parse::<i64>())) {
+            Some((_, Ok(n))) => s.serialize_i64(n),
+            Some((v, Err(_))) => s.serialize_str(v),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+        Ok(Some(match Raw::deserialize(d)? {
+            Raw::Int(n) => n.to_string(),
+            Raw::Str(s) => s,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers: Answers = toml::from_str("part1 = 7007\npart2 = \"ABC\"").unwrap();
+        assert_eq!(answers.get(1), Some("7007"));
+        assert_eq!(answers.get(2), Some("ABC"));
+
+        let answers: Answers = toml::from_str("part1 = 42").unwrap();
+        assert_eq!(answers.get(2), None);
+        assert_eq!(toml::to_string(&answers).unwrap(), "part1 = 42\n");
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers {
+            part1: Some("7007".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(answers.verify(1, &Answer::from(7007)), Verdict::Pass);
+        assert_eq!(answers.verify(1, &Answer::from(7008)), Verdict::Fail);
+        assert_eq!(answers.verify(2, &Answer::from(1)), Verdict::Missing);
+
+        let timeout = Err(PartError::TimedOut(Duration::from_secs(1)));
+        assert_eq!(answers.check(1, &timeout), Some(Verdict::Timeout));
+        let panicked = Err(PartError::Panicked("oops".to_string()));
+        assert_eq!(answers.check(1, &panicked), None);
+    }
+
+    #[test]
+    fn test_known_wrong() {
+        let answers: Answers = toml::from_str(
+            r#"
+            part1 = 10
+
+            [[wrong]]
+            part = 2
+            answer = "1218"
+            hint = "too high"
+
+            [[wrong]]
+            part = 2
+            answer = "400"
+            hint = "too low"
+
+            [[wrong]]
+            part = 2
+            answer = "ABC"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.known_wrong(2, "500"), None);
+        assert_eq!(answers.known_wrong(1, "1218"), None);
+        assert_eq!(
+            answers.known_wrong(2, "1218"),
+            Some("1218 was already rejected".to_string())
+        );
+        assert_eq!(
+            answers.known_wrong(2, "2000"),
+            Some("1218 was too high already".to_string())
+        );
+        assert_eq!(
+            answers.known_wrong(2, "399"),
+            Some("400 was too low already".to_string())
+        );
+        assert_eq!(
+            answers.known_wrong(2, "ABC"),
+            Some("ABC was already rejected".to_string())
+        );
+    }
+
+    #[test]
+    fn test_save() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let mut answers = Answers::default();
+        answers.set(1, "42\n");
+        answers.add_wrong(2, "7", Some(Hint::TooLow));
+        answers.save(&path).unwrap();
+
+        let loaded = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, answers);
+        assert_eq!(loaded.get(1), Some("42"));
+    }
+
+    #[test]
+    fn test_path_for() {
+        assert_eq!(
+            Answers::path_for("inputs/day-18/alice.txt"),
+            PathBuf::from("inputs/day-18/alice.answers.toml")
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let answers = Answers::load("does/not/exist/answers.toml").unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// How long a benchmark keeps sampling after the warm-up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Budget {
+    Runs(usize),
+    Time(Duration),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    pub warmup: usize,
+    pub budget: Budget,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            budget: Budget::Time(Duration::from_secs(1)),
+        }
+    }
+}
+
+impl Bench {
+    /// Runs `f` until the budget is exhausted (always at least once) and
+    /// summarizes the wall-clock time of each run.
+    pub fn run<T>(&self, mut f: impl FnMut() -> T) -> Stats {
+        for _ in 0..self.warmup {
+            black_box(f());
+        }
+
+        let mut samples = Vec::new();
+        let start = Instant::now();
+        loop {
+            let sample = Instant::now();
+            black_box(f());
+            samples.push(sample.elapsed());
+
+            let done = match self.budget {
+                Budget::Runs(runs) => samples.len() >= runs,
+                Budget::Time(time) => start.elapsed() >= time,
+            };
+            if done {
+                break;
+            }
+        }
+
+        Stats::from_samples(&mut samples)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "No samples to summarize");
+        samples.sort();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        // Nearest-rank percentile
+        let p95 = samples[((n as f64 * 0.95).ceil() as usize).max(1) - 1];
+
+        Self {
+            samples: n,
+            min: samples[0],
+            median,
+            mean,
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1_000.0
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.3}ms, median {:.3}ms, mean {:.3}ms, p95 {:.3}ms, stddev {:.3}ms ({} runs)",
+            millis(self.min),
+            millis(self.median),
+            millis(self.mean),
+            millis(self.p95),
+            millis(self.stddev),
+            self.samples
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&mut ms(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert_eq!(stats.stddev.as_micros(), 1414);
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let stats = Stats::from_samples(&mut ms(&[4, 1, 3, 2]));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.p95, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn test_bench_runs() {
+        let mut calls = 0;
+        let bench = Bench {
+            warmup: 2,
+            budget: Budget::Runs(10),
+        };
+        let stats = bench.run(|| calls += 1);
+        assert_eq!(calls, 12);
+        assert_eq!(stats.samples, 10);
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{span, CountUntil};
+
+thread_local! {
+    static CURRENT: RefCell<CancelToken> = RefCell::new(CancelToken::new());
+}
+
+/// Tells a long running solution that nobody waits for its answer anymore.
+/// Solutions poll it from their main loop and bail out with any answer, which
+/// is then thrown away:
+///
+/// ```ignore
+/// let cancel = runner::cancel_token();
+/// for i in 0..4_000_000_000 {
+///     if cancel.is_cancelled() {
+///         return 0;
+///     }
+///     ...
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn flag(&self) -> &AtomicBool {
+        &self.0
+    }
+}
+
+/// The token of the part running on this thread, which is never cancelled
+/// unless it runs under [`with_timeout`].
+pub fn cancel_token() -> CancelToken {
+    CURRENT.with_borrow(|token| token.clone())
+}
+
+/// Shorthand for `cancel_token().is_cancelled()`, for loops that are not hot
+/// enough to keep the token around.
+pub fn cancelled() -> bool {
+    CURRENT.with_borrow(|token| token.is_cancelled())
+}
+
+/// Why a part has no answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PartError {
+    /// It did not finish in time
+    TimedOut(Duration),
+    /// It panicked, with this message
+    Panicked(String),
+}
+
+impl Display for PartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartError::TimedOut(timeout) => write!(f, "timed out after {:?}", timeout),
+            PartError::Panicked(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+/// The message a panic was raised with.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Runs `f`, turning a panic into an error so that the other parts and days
+/// still run. The panic is still printed, with its backtrace if enabled.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, PartError> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(|payload| PartError::Panicked(panic_message(payload.as_ref())))
+}
+
+/// Runs `f` on its own thread, giving up on it after `timeout`: its token is
+/// cancelled and the thread is left behind, to stop on its own if it polls.
+/// One that never polls keeps spinning until the process exits, slowing down
+/// whatever runs next in it, but its allocations are no longer counted. Spans
+/// opened by `f` are recorded under the span open on this thread, and a panic
+/// in `f` is returned as an error.
+pub fn with_timeout<T: Send + 'static>(
+    timeout: Duration,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, PartError> {
+    let token = CancelToken::new();
+    let origin = span::origin();
+    let (tx, rx) = mpsc::channel();
+
+    let worker_token = token.clone();
+    thread::spawn(move || {
+        let _counting = CountUntil::new(worker_token.clone());
+        CURRENT.set(worker_token);
+        let start = Instant::now();
+        if let Some(origin) = origin {
+            span::begin("worker", origin);
+        }
+        let result = catch_panic(f);
+        let timing = span::finish(start.elapsed());
+        // Nobody listens anymore if it timed out
+        let _ = tx.send((result, timing));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok((result, timing)) => {
+            if let Some(timing) = timing {
+                span::graft(timing.children);
+            }
+            result
+        }
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            token.cancel();
+            Err(PartError::TimedOut(timeout))
+        }
+        // Only if the thread died outside of `f`, as its panics are caught
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Err(PartError::Panicked("the part's thread died".to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finished() {
+        assert_eq!(with_timeout(Duration::from_secs(5), || 42), Ok(42));
+        assert!(!cancelled());
+    }
+
+    #[test]
+    fn test_timed_out() {
+        let (tx, rx) = mpsc::channel();
+        let result = with_timeout(Duration::from_millis(10), move || {
+            let token = cancel_token();
+            while !token.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            tx.send(()).unwrap();
+        });
+
+        assert_eq!(result, Err(PartError::TimedOut(Duration::from_millis(10))));
+        // The part saw the cancellation and stopped
+        rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    }
+
+    #[test]
+    fn test_panicked() {
+        let result = with_timeout(Duration::from_secs(5), || -> u32 { panic!("no {}", "way") });
+        assert_eq!(result, Err(PartError::Panicked("no way".to_string())));
+        assert_eq!(
+            catch_panic(|| -> u32 { panic!("oops") }),
+            Err(PartError::Panicked("oops".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_spans() {
+        let runner = crate::Runner::start();
+        {
+            let _part = runner.span("part 1");
+            with_timeout(Duration::from_secs(5), || {
+                let _bfs = span::span("bfs");
+            })
+            .unwrap();
+        }
+        let timing = runner.finish();
+
+        assert_eq!(timing.children[0].name, "part 1");
+        assert_eq!(timing.children[0].children[0].name, "bfs");
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+};
+
+use crate::{panic_message, Answer, Day};
+
+/// Another implementation of a part, kept to check the registered one
+/// against, see [`Solution::alternatives`]:
+///
+/// ```ignore
+/// Alternative {
+///     part: 1,
+///     name: "brute force",
+///     solve: |rows| rows.iter().map(part1::solve_row).sum::<u32>().into(),
+/// }
+/// ```
+///
+/// [`Solution::alternatives`]: crate::Solution::alternatives
+pub struct Alternative<I> {
+    pub part: u8,
+    pub name: &'static str,
+    pub solve: fn(&I) -> Answer,
+}
+
+/// An input the implementations of a part do not agree on, with what each of
+/// them gave, the registered one first. A panic counts as an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: u8,
+    pub input: String,
+    pub answers: Vec<(String, Result<Answer, String>)>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.answers.iter().map(|(name, _)| name.len()).max();
+        for (name, answer) in &self.answers {
+            let width = width.unwrap_or(0);
+            match answer {
+                Ok(answer) if answer.is_multiline() => writeln!(f, "{}\n{}", name, answer)?,
+                Ok(answer) => writeln!(f, "{:<width$}  {}", name, answer)?,
+                Err(panic) => writeln!(f, "{:<width$}  panicked: {}", name, panic)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Solves `part` of `input` with every implementation of it, returning what
+/// they gave if they do not all agree. Inputs that do not parse, or make the
+/// parser panic, are not disagreements.
+pub fn compare(day: &Day, part: u8, input: &str) -> Option<Disagreement> {
+    let parsed = quietly(|| day.parse(input)).ok()?.ok()?;
+    let mut answers = vec![(
+        format!("part {}", part),
+        quietly(|| day.solve(part, &parsed)),
+    )];
+    for (index, name) in day.alternatives(part).into_iter().enumerate() {
+        let answer = quietly(|| day.solve_alternative(part, index, &parsed));
+        answers.push((name.to_string(), answer));
+    }
+
+    let agree = answers.windows(2).all(|pair| pair[0].1 == pair[1].1);
+    (!agree).then(|| Disagreement {
+        part,
+        input: input.to_string(),
+        answers,
+    })
+}
+
+/// Looks for the smallest input the implementations still disagree on, by
+/// dropping lines, then characters, in ever smaller chunks.
+pub fn shrink(day: &Day, disagreement: Disagreement) -> Disagreement {
+    let part = disagreement.part;
+    let lines = disagreement
+        .input
+        .lines()
+        .map(|line| format!("{}\n", line))
+        .collect();
+    let mut smallest = disagreement;
+    let lines = drop_chunks(lines, |input| compare(day, part, input), &mut smallest);
+    let chars = lines.concat().chars().map(String::from).collect();
+    drop_chunks(chars, |input| compare(day, part, input), &mut smallest);
+    smallest
+}
+
+/// Drops chunks of `pieces` as long as what is left is still a disagreement,
+/// halving the chunks down to single pieces. Returns the pieces left, and
+/// keeps the last disagreement in `smallest`.
+fn drop_chunks(
+    mut pieces: Vec<String>,
+    mut disagree: impl FnMut(&str) -> Option<Disagreement>,
+    smallest: &mut Disagreement,
+) -> Vec<String> {
+    let mut chunk = pieces.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < pieces.len() && pieces.len() > 1 {
+            let end = (start + chunk).min(pieces.len());
+            let candidate = [&pieces[..start], &pieces[end..]].concat();
+            match disagree(&candidate.concat()) {
+                Some(disagreement) => {
+                    pieces = candidate;
+                    *smallest = disagreement;
+                }
+                None => start = end,
+            }
+        }
+        chunk /= 2;
+    }
+    pieces
+}
+
+/// Held while the panic hook is swapped, so that two threads comparing at
+/// once do not leave it silenced.
+static HOOK: Mutex<()> = Mutex::new(());
+
+/// Runs `f`, turning a panic into its message without printing it, as
+/// shrinking makes implementations panic on purpose.
+fn quietly<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let _lock = HOOK.lock().unwrap_or_else(|err| err.into_inner());
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+
+    result.map_err(|payload| panic_message(payload.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Solution};
+
+    /// Counts the lines with a `#`, with an alternative that is wrong when a
+    /// line also holds an `x`, and one that panics on `!`.
+    struct Hashes;
+
+    impl Solution for Hashes {
+        const DAY: u8 = 1;
+
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part1(lines: &Self::Input) -> impl Into<Answer> {
+            lines.iter().filter(|line| line.contains('#')).count()
+        }
+
+        fn part2(_: &Self::Input) -> impl Into<Answer> {}
+
+        fn alternatives() -> Vec<Alternative<Self::Input>> {
+            vec![
+                Alternative {
+                    part: 1,
+                    name: "sloppy",
+                    solve: |lines| {
+                        let hashes = lines.iter().filter(|l| l.contains('#') && !l.contains('x'));
+                        hashes.count().into()
+                    },
+                },
+                Alternative {
+                    part: 1,
+                    name: "fragile",
+                    solve: |lines| {
+                        assert!(!lines.concat().contains('!'), "found a !");
+                        lines
+                            .iter()
+                            .filter(|line| line.contains('#'))
+                            .count()
+                            .into()
+                    },
+                },
+            ]
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let day = Day::new::<Hashes>();
+        assert_eq!(day.alternatives(1), vec!["sloppy", "fragile"]);
+        assert_eq!(day.alternatives(2), Vec::<&str>::new());
+        assert_eq!(compare(&day, 1, "#\n.\n##\n"), None);
+        assert_eq!(compare(&day, 2, "#x\n"), None);
+
+        let disagreement = compare(&day, 1, "#\n!\n").unwrap();
+        assert_eq!(disagreement.answers[2].1, Err("found a !".to_string()));
+    }
+
+    #[test]
+    fn test_shrink() {
+        let day = Day::new::<Hashes>();
+        let input = "#..\n...\n.#.x\nabc\n##\n";
+        let disagreement = shrink(&day, compare(&day, 1, input).unwrap());
+
+        assert_eq!(disagreement.input, "#x");
+        assert_eq!(
+            disagreement.to_string(),
+            "part 1   1\nsloppy   0\nfragile  1\n"
+        );
+    }
+}
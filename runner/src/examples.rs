@@ -0,0 +1,434 @@
+use std::{collections::BTreeMap, fs, io, path::Path, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{answers::answer, Solution};
+
+pub const EXAMPLES_FILE: &str = "examples.toml";
+
+/// Examples from a day's puzzle description, stored as `day-x/examples.toml`
+/// next to the input files it lists:
+///
+/// ```toml
+/// [[example]]
+/// input = "example.txt"
+/// part1 = 142
+///
+/// [[example]]
+/// input = "example2.txt"
+/// part2 = 281
+/// params = { steps = 6 }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Examples {
+    #[serde(default, rename = "example")]
+    pub examples: Vec<Example>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Example {
+    /// File holding the example input, relative to the manifest
+    pub input: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "answer")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "answer")]
+    pub part2: Option<String>,
+    #[serde(default, skip_serializing_if = "Params::is_empty")]
+    pub params: Params,
+}
+
+/// Settings an example is solved with instead of the ones of the real input,
+/// such as a smaller number of steps. See [`Solution::example`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Params(#[serde(deserialize_with = "params")] BTreeMap<String, String>);
+
+impl Params {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Parses the named parameter, panicking if it is not a `T`.
+    pub fn get<T: FromStr>(&self, name: &str) -> Option<T> {
+        let value = self.0.get(name)?;
+        match value.parse() {
+            Ok(value) => Some(value),
+            Err(_) => panic!("Invalid value for param {}: {}", name, value),
+        }
+    }
+}
+
+impl<const N: usize> From<[(&str, &str); N]> for Params {
+    fn from(params: [(&str, &str); N]) -> Self {
+        Self::from(&params[..])
+    }
+}
+
+impl From<&[(&str, &str)]> for Params {
+    fn from(params: &[(&str, &str)]) -> Self {
+        Self(
+            params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+}
+
+/// Like answers, parameters are kept as text whatever their TOML type.
+fn params<'de, D: serde::Deserializer<'de>>(d: D) -> Result<BTreeMap<String, String>, D::Error> {
+    let params = BTreeMap::<String, toml::Value>::deserialize(d)?;
+    Ok(params
+        .into_iter()
+        .map(|(name, value)| match value {
+            toml::Value::String(s) => (name, s),
+            value => (name, value.to_string()),
+        })
+        .collect())
+}
+
+/// Solves one example and checks its answer. This is what the tests generated
+/// by [`example_tests!`](crate::example_tests) call.
+pub fn check_example<S: Solution>(input: &str, part: u8, expected: &str, params: &[(&str, &str)]) {
+    let input = S::parse(input).unwrap_or_else(|err| panic!("Day {}: {}", S::DAY, err));
+    let actual = S::example(part, &input, &Params::from(params));
+    assert!(
+        actual.matches(expected),
+        "Day {} part {}: expected {}, got {}",
+        S::DAY,
+        part,
+        expected,
+        actual
+    );
+}
+
+/// An example as found in the puzzle description, before being written out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageExample {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl PageExample {
+    fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            _ => self.part2 = Some(answer),
+        }
+    }
+}
+
+impl Examples {
+    /// Reads the manifest in `dir`. A missing file means no examples.
+    pub fn load(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let path = dir.as_ref().join(EXAMPLES_FILE);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+
+        toml::from_str(&content).map_err(|err| {
+            let msg = format!("{}: {}", path.display(), err);
+            io::Error::new(io::ErrorKind::InvalidData, msg)
+        })
+    }
+
+    /// Writes every example to the first free `example.txt`, `example2.txt`...
+    /// in `dir`, and lists it in the manifest. An existing manifest is added
+    /// to, keeping what it holds (such as `params`), and examples whose input
+    /// it already lists are skipped. Returns the examples added.
+    pub fn write(dir: impl AsRef<Path>, found: &[PageExample]) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let existing = Self::load(dir)?;
+        let mut listed = existing
+            .examples
+            .iter()
+            .filter_map(|example| fs::read_to_string(dir.join(&example.input)).ok())
+            .collect::<Vec<_>>();
+        let free = |name: &String| {
+            !dir.join(name).exists() && existing.examples.iter().all(|e| &e.input != name)
+        };
+        let mut names = (1..)
+            .map(|i| match i {
+                1 => "example.txt".to_string(),
+                i => format!("example{}.txt", i),
+            })
+            .filter(free);
+
+        let mut added = Self::default();
+        for example in found {
+            if listed.contains(&example.input) {
+                continue;
+            }
+            let Some(input) = names.next() else {
+                unreachable!("there are endless names");
+            };
+            fs::write(dir.join(&input), &example.input)?;
+            listed.push(example.input.clone());
+            added.examples.push(Example {
+                input,
+                part1: example.part1.clone(),
+                part2: example.part2.clone(),
+                params: Params::default(),
+            });
+        }
+        if added.examples.is_empty() {
+            return Ok(added);
+        }
+
+        // Appended as text, so that the comments of the manifest stay
+        let path = dir.join(EXAMPLES_FILE);
+        let mut content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+        if !content.is_empty() {
+            content = format!("{}\n\n", content.trim_end());
+        }
+        content.push_str(&toml::to_string(&added).map_err(io::Error::other)?);
+        fs::write(path, content)?;
+        Ok(added)
+    }
+
+    /// Finds the examples in a saved puzzle page, with their answers.
+    ///
+    /// Each part is an `<article>`: its examples are the `<pre><code>` blocks,
+    /// and its answer is the last emphasized `<code>` outside of them, which
+    /// belongs to the closest example before it (or to the one of part one,
+    /// when part two has none). Blocks without an answer are usually
+    /// illustrations and are left out.
+    pub fn extract(page: &str) -> Vec<PageExample> {
+        let mut found: Vec<PageExample> = vec![];
+
+        for (part, article) in (1..=2).zip(articles(page)) {
+            let mut latest = None;
+            let mut answer = None;
+
+            let mut rest = article;
+            loop {
+                let pre = rest.split_once("<pre><code>");
+                let prose = pre.map_or(rest, |(prose, _)| prose);
+                if let Some(emphasized) = last_emphasized(prose) {
+                    answer = Some((latest, emphasized));
+                }
+
+                let Some((block, after)) = pre.and_then(|(_, r)| r.split_once("</code></pre>"))
+                else {
+                    break;
+                };
+                latest = Some(text(block));
+                rest = after;
+            }
+
+            let Some((input, answer)) = answer else {
+                continue;
+            };
+            let input = input.or_else(|| found.last().map(|e| e.input.clone()));
+            let Some(input) = input else {
+                continue;
+            };
+            match found.iter_mut().find(|e| e.input == input) {
+                Some(example) => example.set(part, answer),
+                None => {
+                    let mut example = PageExample {
+                        input,
+                        ..Default::default()
+                    };
+                    example.set(part, answer);
+                    found.push(example);
+                }
+            }
+        }
+
+        found
+    }
+}
+
+impl Example {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => panic!("Invalid part: {}", part),
+        }
+    }
+}
+
+fn articles(page: &str) -> Vec<&str> {
+    page.split("<article")
+        .skip(1)
+        .filter_map(|rest| rest.split_once('>'))
+        .filter_map(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article)
+        .collect()
+}
+
+/// The last `<code><em>..</em></code>` (or the other way round) in `html`.
+fn last_emphasized(html: &str) -> Option<String> {
+    [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ]
+    .into_iter()
+    .filter_map(|(open, close)| {
+        let start = html.rfind(open)?;
+        let (value, _) = html[start + open.len()..].split_once(close)?;
+        Some((start, value))
+    })
+    .max_by_key(|&(start, _)| start)
+    .map(|(_, value)| text(value).trim().to_string())
+}
+
+/// Strips the tags from `html` and decodes the entities the site uses.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>For example:</p>
+<pre><code>1abc2
+a1b&lt;2&gt;c3
+</code></pre>
+<p>The values are <code>12</code> and <code>13</code>. Adding these together produces <code><em>25</em></code>.</p>
+<p><em>What is the sum of all of the calibration values?</em></p>
+</article>
+<p>Your puzzle answer was <code>54601</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>For example:</p>
+<pre><code><em>two</em>1nine
+</code></pre>
+<p>Adding these together produces <em><code>29</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            Examples::extract(PAGE),
+            vec![
+                PageExample {
+                    input: "1abc2\na1b<2>c3\n".to_string(),
+                    part1: Some("25".to_string()),
+                    part2: None,
+                },
+                PageExample {
+                    input: "two1nine\n".to_string(),
+                    part1: None,
+                    part2: Some("29".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_shared_example() {
+        let page = r#"
+<article class="day-desc"><pre><code>#.#
+</code></pre><p>Draw it:</p><pre><code>O.O
+</code></pre><p>The total is <code><em>4</em></code>.</p></article>
+<article class="day-desc"><p>Now it is <code><em>10</em></code>.</p></article>
+"#;
+        assert_eq!(
+            Examples::extract(page),
+            vec![PageExample {
+                input: "O.O\n".to_string(),
+                part1: Some("4".to_string()),
+                part2: Some("10".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_params() {
+        let examples: Examples = toml::from_str(
+            r#"
+            [[example]]
+            input = "test.txt"
+            part2 = 1030
+            params = { expansion = 10, name = "abc" }
+            "#,
+        )
+        .unwrap();
+
+        let params = &examples.examples[0].params;
+        assert_eq!(params.get::<u64>("expansion"), Some(10));
+        assert_eq!(params.get::<String>("name"), Some("abc".to_string()));
+        assert_eq!(params.get::<u64>("steps"), None);
+        assert_eq!(
+            params,
+            &Params::from([("expansion", "10"), ("name", "abc")])
+        );
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let written = Examples::write(&dir, &Examples::extract(PAGE)).unwrap();
+        let loaded = Examples::load(&dir).unwrap();
+        let input = fs::read_to_string(dir.join("example2.txt")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded, written);
+        assert_eq!(loaded.examples[0].input, "example.txt");
+        assert_eq!(loaded.examples[0].get(1), Some("25"));
+        assert_eq!(loaded.examples[1].get(2), Some("29"));
+        assert_eq!(input, "two1nine\n");
+    }
+
+    #[test]
+    fn test_write_existing() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-add-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let manifest = "# Hand-written\n\
+            [[example]]\n\
+            input = \"test.txt\"\n\
+            part2 = 1030\n\
+            params = { expansion = 10 }\n";
+        fs::write(dir.join(EXAMPLES_FILE), manifest).unwrap();
+        fs::write(dir.join("test.txt"), "two1nine\n").unwrap();
+        fs::write(dir.join("example.txt"), "not listed\n").unwrap();
+
+        // The second example of the page is already listed as `test.txt`
+        let added = Examples::write(&dir, &Examples::extract(PAGE)).unwrap();
+        let loaded = Examples::load(&dir).unwrap();
+        let content = fs::read_to_string(dir.join(EXAMPLES_FILE)).unwrap();
+        let again = Examples::write(&dir, &Examples::extract(PAGE)).unwrap();
+        let unchanged = fs::read_to_string(dir.join(EXAMPLES_FILE)).unwrap();
+        let unlisted = fs::read_to_string(dir.join("example.txt")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(added.examples.len(), 1);
+        assert_eq!(added.examples[0].input, "example2.txt");
+        assert!(content.starts_with(manifest));
+        assert_eq!(loaded.examples.len(), 2);
+        assert_eq!(
+            loaded.examples[0].params,
+            Params::from([("expansion", "10")])
+        );
+        assert_eq!(loaded.examples[1], added.examples[0]);
+        assert_eq!(again, Examples::default());
+        assert_eq!(unchanged, content);
+        assert_eq!(unlisted, "not listed\n");
+    }
+}
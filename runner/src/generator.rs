@@ -0,0 +1,96 @@
+use std::{
+    ops::Range,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Random numbers for writing inputs, see [`Solution::generate`]. The same
+/// seed gives the same input on every machine, so a slow input can be shared
+/// as its size and seed.
+///
+/// [`Solution::generate`]: crate::Solution::generate
+pub struct Generator {
+    state: u64,
+    size: usize,
+}
+
+impl Generator {
+    pub fn new(seed: u64, size: usize) -> Self {
+        Self { state: seed, size }
+    }
+
+    /// A seed that differs from run to run.
+    pub fn random_seed() -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        Self::new(now.as_nanos() as u64, 0).next_u64()
+    }
+
+    /// How big the input should be, in whatever unit suits the day: lines,
+    /// records, or the range of its numbers.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// SplitMix64, small and good enough for test data.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "Empty range: {:?}", range);
+        let len = range.end - range.start;
+        range.start + ((self.next_u64() as u128 * len as u128) >> 64) as u64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i as u64 + 1) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let numbers = |seed| {
+            let mut gen = Generator::new(seed, 10);
+            (0..5).map(|_| gen.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(42), numbers(42));
+        assert_ne!(numbers(42), numbers(43));
+        // Generated inputs are shared as their seed, which must not change
+        assert_eq!(Generator::new(0, 0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut gen = Generator::new(7, 0);
+        let rolls = (0..1000).map(|_| gen.range(1..7)).collect::<Vec<_>>();
+        assert!(rolls.iter().all(|r| (1..7).contains(r)));
+        assert!((1..7).all(|face| rolls.contains(&face)));
+
+        let mut items = [1, 2, 3, 4, 5];
+        gen.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+        assert!(!gen.chance(0.0) && gen.chance(1.0));
+    }
+}
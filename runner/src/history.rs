@@ -0,0 +1,240 @@
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::Stats;
+
+const HEADER: &str = "day,phase,rev,label,samples,min_ns,median_ns,mean_ns,p95_ns,stddev_ns";
+
+/// What a benchmark record measures: the parse, or one of the parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// A single benchmark result, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub phase: Phase,
+    /// Git revision the benchmark was run on
+    pub rev: String,
+    /// Optional name to compare against later, e.g. `baseline`
+    pub label: String,
+    pub stats: Stats,
+}
+
+impl Record {
+    fn to_csv(&self) -> String {
+        let phase = match self.phase {
+            Phase::Parse => "parse".to_string(),
+            Phase::Part(part) => part.to_string(),
+        };
+        let s = &self.stats;
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.day,
+            phase,
+            self.rev,
+            self.label,
+            s.samples,
+            s.min.as_nanos(),
+            s.median.as_nanos(),
+            s.mean.as_nanos(),
+            s.p95.as_nanos(),
+            s.stddev.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields = line.split(',').collect::<Vec<_>>();
+        let [day, phase, rev, label, samples, min, median, mean, p95, stddev] = fields[..] else {
+            return Err(format!("expected 10 fields, found {}", fields.len()));
+        };
+
+        let number = |s: &str| {
+            s.parse::<u64>()
+                .map_err(|_| format!("invalid number '{}'", s))
+        };
+        let nanos = |s: &str| number(s).map(Duration::from_nanos);
+
+        Ok(Record {
+            day: number(day)? as u8,
+            phase: match phase {
+                "parse" => Phase::Parse,
+                part => Phase::Part(number(part)? as u8),
+            },
+            rev: rev.to_string(),
+            label: label.to_string(),
+            stats: Stats {
+                samples: number(samples)? as usize,
+                min: nanos(min)?,
+                median: nanos(median)?,
+                mean: nanos(mean)?,
+                p95: nanos(p95)?,
+                stddev: nanos(stddev)?,
+            },
+        })
+    }
+}
+
+/// Append-only CSV log of benchmark results.
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads every record, oldest first. A missing file is an empty history.
+    pub fn load(&self) -> io::Result<Vec<Record>> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err),
+        };
+
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && *line != HEADER)
+            .map(|(i, line)| {
+                line.parse().map_err(|err| {
+                    let msg = format!("{}:{}: {}", self.path.display(), i + 1, err);
+                    io::Error::new(io::ErrorKind::InvalidData, msg)
+                })
+            })
+            .collect()
+    }
+
+    pub fn append(&self, records: &[Record]) -> io::Result<()> {
+        let exists = self.path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        if !exists {
+            writeln!(file, "{}", HEADER)?;
+        }
+        for record in records {
+            writeln!(file, "{}", record.to_csv())?;
+        }
+        Ok(())
+    }
+
+    /// The latest record for the given day and phase whose label or revision
+    /// matches `baseline`.
+    pub fn baseline<'a>(
+        records: &'a [Record],
+        baseline: &str,
+        day: u8,
+        phase: Phase,
+    ) -> Option<&'a Record> {
+        records.iter().rev().find(|r| {
+            r.day == day && r.phase == phase && (r.label == baseline || r.rev == baseline)
+        })
+    }
+}
+
+/// Relative change of the median from `baseline` to `current`, in percent.
+/// Positive means slower.
+pub fn regression(baseline: &Stats, current: &Stats) -> f64 {
+    let base = baseline.median.as_nanos() as f64;
+    if base == 0.0 {
+        return 0.0;
+    }
+    (current.median.as_nanos() as f64 - base) * 100.0 / base
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median_ms: u64) -> Stats {
+        let d = Duration::from_millis(median_ms);
+        Stats {
+            samples: 10,
+            min: d,
+            median: d,
+            mean: d,
+            p95: d,
+            stddev: Duration::ZERO,
+        }
+    }
+
+    fn record(phase: Phase, rev: &str, label: &str, median_ms: u64) -> Record {
+        Record {
+            day: 12,
+            phase,
+            rev: rev.to_string(),
+            label: label.to_string(),
+            stats: stats(median_ms),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.csv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let history = History::new(&path);
+        assert_eq!(history.load().unwrap(), vec![]);
+
+        let first = vec![record(Phase::Parse, "abc123", "baseline", 2)];
+        let second = vec![record(Phase::Part(2), "def456", "", 5)];
+        history.append(&first).unwrap();
+        history.append(&second).unwrap();
+
+        let records = history.load().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(records, [first, second].concat());
+    }
+
+    #[test]
+    fn test_baseline() {
+        let records = vec![
+            record(Phase::Part(1), "abc123", "baseline", 10),
+            record(Phase::Part(1), "def456", "baseline", 20),
+            record(Phase::Part(1), "0a0b0c", "", 30),
+        ];
+
+        let found = History::baseline(&records, "baseline", 12, Phase::Part(1)).unwrap();
+        assert_eq!(found.rev, "def456");
+        let found = History::baseline(&records, "abc123", 12, Phase::Part(1)).unwrap();
+        assert_eq!(found.stats.median, Duration::from_millis(10));
+        assert!(History::baseline(&records, "baseline", 12, Phase::Part(2)).is_none());
+    }
+
+    #[test]
+    fn test_regression() {
+        assert_eq!(regression(&stats(10), &stats(15)), 50.0);
+        assert_eq!(regression(&stats(10), &stats(5)), -50.0);
+    }
+}
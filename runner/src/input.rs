@@ -0,0 +1,145 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// The conventional location of a day's input, `<inputs>/day-xx/input.txt`.
+    pub fn for_day(inputs: impl AsRef<Path>, day: u8) -> Self {
+        Self::File(
+            inputs
+                .as_ref()
+                .join(format!("day-{:02}", day))
+                .join("input.txt"),
+        )
+    }
+
+    /// `-` stands for stdin, anything else is a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| InputError {
+                        source: self.clone(),
+                        err,
+                    })?;
+                Ok(input)
+            }
+            Self::File(path) => fs::read_to_string(path).map_err(|err| InputError {
+                source: self.clone(),
+                err,
+            }),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "stdin"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub err: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            InputSource::File(path) if self.err.kind() == io::ErrorKind::NotFound => write!(
+                f,
+                "input not found, expected it at {} (pass --input or --inputs to read it from elsewhere)",
+                path.display()
+            ),
+            source => write!(f, "cannot read {}: {}", source, self.err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The inputs in `dir`, every `*.txt` file in it sorted by name, such as the
+/// inputs of several people for the same day.
+pub fn input_files(dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "txt") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Reads the real input of the day crate at `manifest_dir`, for the tests that
+/// check it. Puzzle inputs are not committed, so it is `None` in fresh clones.
+pub fn local_input(manifest_dir: &str) -> Option<String> {
+    let path = Path::new(manifest_dir).join("input.txt");
+    let input = fs::read_to_string(&path).ok();
+    if input.is_none() {
+        eprintln!("Skipping: {} not found", path.display());
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_day() {
+        assert_eq!(
+            InputSource::for_day("inputs", 7),
+            InputSource::File(PathBuf::from("inputs/day-07/input.txt"))
+        );
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_input_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for file in ["bob.txt", "alice.txt", "alice.answers.toml"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        fs::create_dir_all(dir.join("old.txt")).unwrap();
+
+        let files = input_files(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(files, vec![dir.join("alice.txt"), dir.join("bob.txt")]);
+        assert!(input_files(dir).is_err());
+    }
+
+    #[test]
+    fn test_missing() {
+        let err = InputSource::for_day("nowhere", 18).read().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input not found, expected it at nowhere/day-18/input.txt (pass --input or --inputs to read it from elsewhere)"
+        );
+    }
+}
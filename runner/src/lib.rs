@@ -1,4 +1,40 @@
-use std::time::Instant;
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod differential;
+pub mod examples;
+pub mod generator;
+pub mod history;
+pub mod input;
+pub mod progress;
+pub mod registry;
+pub mod solution;
+pub mod span;
+
+pub use alloc::*;
+pub use answer::*;
+pub use answers::*;
+pub use bench::*;
+pub use cancel::*;
+pub use differential::*;
+pub use examples::*;
+pub use generator::*;
+pub use history::*;
+pub use input::*;
+pub use progress::*;
+pub use registry::*;
+pub use solution::*;
+pub use span::*;
+
+pub use runner_macros::example_tests;
+pub use toolkit::parse::ParseError;
+
+use std::{
+    borrow::Cow,
+    time::{Duration, Instant},
+};
 
 #[derive(Clone, Copy)]
 pub struct Runner {
@@ -6,18 +42,46 @@ pub struct Runner {
 }
 
 impl Runner {
-    // Starts timing
+    /// Starts timing, and recording the spans opened on this thread.
     pub fn start() -> Self {
-        Self {
-            start: Instant::now(),
-        }
+        let start = Instant::now();
+        span::begin("total", start);
+        Self { start }
     }
 
-    pub fn end(&self) {
-        println!(
-            "Elapsed: {:.3}ms",
-            self.start.elapsed().as_micros() as f64 / 1_000.0
-        );
+    /// Opens a span, see [`span()`] to open one where the runner is not at hand.
+    pub fn span(&self, name: impl Into<Cow<'static, str>>) -> Span {
+        span(name)
+    }
+
+    /// Stops recording, returning the timing tree of the run.
+    pub fn finish(&self) -> Timing {
+        let elapsed = self.start.elapsed();
+        span::finish(elapsed).unwrap_or_else(|| Timing {
+            name: "total".into(),
+            elapsed,
+            runs: vec![Run {
+                start: Duration::ZERO,
+                elapsed,
+            }],
+            alloc: None,
+            children: vec![],
+        })
+    }
+
+    /// Prints the elapsed time, broken down by span if any was opened or if
+    /// allocations are counted, and returns the timing tree for exporting.
+    pub fn end(&self) -> Timing {
+        let tree = self.finish();
+        if tree.children.is_empty() && tree.alloc.is_none() {
+            println!(
+                "Elapsed: {:.3}ms",
+                tree.elapsed.as_micros() as f64 / 1_000.0
+            );
+        } else {
+            print!("{}", tree);
+        }
+        tree
     }
 }
 
@@ -0,0 +1,202 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+/// How often the bar is redrawn.
+const REFRESH: Duration = Duration::from_millis(100);
+
+/// Most ticks skipped between two looks at the clock, so that the bar still
+/// moves if the loop slows down.
+const MAX_STRIDE: u64 = 1 << 20;
+
+/// A progress bar for long loops, drawn on stderr with the throughput and the
+/// time left. It stays silent when stderr is not a terminal, and only looks
+/// at the clock every so many ticks, so it can be ticked from hot loops:
+///
+/// ```ignore
+/// let mut progress = runner::Progress::new("cycles");
+/// for i in 0..1_000_000_000 {
+///     progress.tick(i, 1_000_000_000);
+///     ...
+/// }
+/// ```
+pub struct Progress {
+    label: &'static str,
+    enabled: bool,
+    start: Instant,
+    last_draw: Instant,
+    /// When the clock was last looked at, `skipped` ticks ago
+    last_check: Instant,
+    /// Ticks to skip before looking at the clock again
+    stride: u64,
+    skipped: u64,
+    drawn: bool,
+}
+
+impl Progress {
+    pub fn new(label: &'static str) -> Self {
+        Self::with_output(label, io::stderr().is_terminal())
+    }
+
+    fn with_output(label: &'static str, enabled: bool) -> Self {
+        let now = Instant::now();
+        Self {
+            label,
+            enabled,
+            start: now,
+            last_draw: now,
+            last_check: now,
+            stride: 1,
+            skipped: 0,
+            drawn: false,
+        }
+    }
+
+    /// Reports that `done` steps out of `total` are done. Pass `None` as total
+    /// for loops that run until something happens.
+    pub fn tick(&mut self, done: u64, total: impl Into<Option<u64>>) {
+        if !self.enabled {
+            return;
+        }
+        self.skipped += 1;
+        if self.skipped < self.stride {
+            return;
+        }
+
+        let now = Instant::now();
+        self.stride = next_stride(self.stride, now - self.last_check, self.skipped);
+        self.last_check = now;
+        self.skipped = 0;
+        if now - self.last_draw < REFRESH {
+            return;
+        }
+
+        self.last_draw = now;
+        self.drawn = true;
+        let line = render(self.label, done, total.into(), now - self.start);
+        let _ = write!(io::stderr(), "\r\x1b[2K{}", line);
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.drawn {
+            let _ = write!(io::stderr(), "\r\x1b[2K");
+        }
+    }
+}
+
+/// The stride that looks at the clock about ten times per refresh, given how
+/// long the last `skipped` ticks took.
+fn next_stride(stride: u64, elapsed: Duration, skipped: u64) -> u64 {
+    let per_tick = elapsed.as_nanos() / skipped.max(1) as u128;
+    match per_tick {
+        0 => (stride * 2).min(MAX_STRIDE),
+        nanos => ((REFRESH.as_nanos() / 10 / nanos) as u64).clamp(1, MAX_STRIDE),
+    }
+}
+
+fn render(label: &str, done: u64, total: Option<u64>, elapsed: Duration) -> String {
+    const WIDTH: usize = 30;
+
+    let rate = done as f64 / elapsed.as_secs_f64().max(f64::MIN_POSITIVE);
+    let Some(total) = total.filter(|&t| t > 0) else {
+        return format!("{} {} {}/s", label, done, si(rate));
+    };
+
+    let ratio = (done as f64 / total as f64).min(1.0);
+    let filled = (ratio * WIDTH as f64) as usize;
+    let bar = format!("{}{}", "#".repeat(filled), "-".repeat(WIDTH - filled));
+    let eta = match rate > 0.0 {
+        true => format_eta(Duration::from_secs_f64(
+            (total.saturating_sub(done) as f64 / rate).min(1e9),
+        )),
+        false => "?".to_string(),
+    };
+    format!(
+        "{} [{}] {:>5.1}% {}/{} {}/s ETA {}",
+        label,
+        bar,
+        ratio * 100.0,
+        done,
+        total,
+        si(rate),
+        eta
+    )
+}
+
+/// Formats a rate with an SI suffix, e.g. `1.2M`.
+fn si(value: f64) -> String {
+    match value {
+        v if v >= 1e9 => format!("{:.1}G", v / 1e9),
+        v if v >= 1e6 => format!("{:.1}M", v / 1e6),
+        v if v >= 1e3 => format!("{:.1}k", v / 1e3),
+        v => format!("{:.0}", v),
+    }
+}
+
+fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    match secs {
+        s if s >= 3600 => format!("{}h{:02}m", s / 3600, s % 3600 / 60),
+        s if s >= 60 => format!("{}m{:02}s", s / 60, s % 60),
+        s => format!("{}s", s),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("cycles", 250, Some(1000), Duration::from_secs(1)),
+            "cycles [#######-----------------------]  25.0% 250/1000 250/s ETA 3s"
+        );
+        assert_eq!(
+            render("presses", 1_500_000, None, Duration::from_secs(2)),
+            "presses 1500000 750.0k/s"
+        );
+        assert_eq!(
+            render("cycles", 0, Some(10), Duration::from_secs(1)),
+            "cycles [------------------------------]   0.0% 0/10 0/s ETA ?"
+        );
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(Duration::from_secs(59)), "59s");
+        assert_eq!(format_eta(Duration::from_secs(192)), "3m12s");
+        assert_eq!(format_eta(Duration::from_secs(7320)), "2h02m");
+    }
+
+    #[test]
+    fn test_silent() {
+        let mut progress = Progress::with_output("cycles", false);
+        for i in 0..1000 {
+            progress.tick(i, 1000);
+        }
+        assert!(!progress.drawn);
+    }
+
+    #[test]
+    fn test_next_stride() {
+        let micros = Duration::from_micros;
+        // 1µs per tick: 10ms between two looks at the clock
+        assert_eq!(next_stride(1, micros(1_000), 1_000), 10_000);
+        assert_eq!(next_stride(10_000, micros(10_000), 10_000), 10_000);
+        assert_eq!(next_stride(64, micros(1_000), 64), 640);
+        assert_eq!(next_stride(8, Duration::from_secs(1), 1), 1);
+        // 1ns per tick would be 10 million ticks
+        assert_eq!(next_stride(1, micros(1), 1_000), MAX_STRIDE);
+
+        // Too fast for the clock to move between two looks at it
+        assert_eq!(next_stride(8, Duration::ZERO, 8), 16);
+        assert_eq!(
+            next_stride(MAX_STRIDE, Duration::ZERO, MAX_STRIDE),
+            MAX_STRIDE
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use std::{any::Any, collections::BTreeMap};
+
+use crate::{Answer, Generator, ParseError, Solution};
+
+/// The parsed input of any day, as returned by [`Day::parse`].
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// A registered [`Solution`], with its input type erased so that every day
+/// can be driven in the same way.
+#[derive(Clone)]
+pub struct Day {
+    pub day: u8,
+    parse: fn(&str) -> Result<ParsedInput, ParseError>,
+    part1: fn(&ParsedInput) -> Answer,
+    part2: fn(&ParsedInput) -> Answer,
+    generate: fn(&mut Generator) -> Option<String>,
+    alternatives: fn(u8) -> Vec<&'static str>,
+    solve_alternative: fn(u8, usize, &ParsedInput) -> Answer,
+}
+
+impl Day {
+    pub fn new<S>() -> Self
+    where
+        S: Solution,
+        S::Input: Send + Sync + 'static,
+    {
+        Self {
+            day: S::DAY,
+            parse: |input| Ok(Box::new(S::parse(input)?)),
+            part1: |input| S::part1(input.downcast_ref().unwrap()).into(),
+            part2: |input| S::part2(input.downcast_ref().unwrap()).into(),
+            generate: S::generate,
+            alternatives: |part| {
+                let alternatives = S::alternatives().into_iter();
+                alternatives
+                    .filter(|a| a.part == part)
+                    .map(|a| a.name)
+                    .collect()
+            },
+            solve_alternative: |part, index, input| {
+                let mut alternatives = S::alternatives().into_iter().filter(|a| a.part == part);
+                let alternative = alternatives.nth(index).expect("Invalid alternative");
+                (alternative.solve)(input.downcast_ref().unwrap())
+            },
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<ParsedInput, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Solves the given part over an input returned by this same day's
+    /// [`Day::parse`].
+    pub fn solve(&self, part: u8, input: &ParsedInput) -> Answer {
+        match part {
+            1 => (self.part1)(input),
+            2 => (self.part2)(input),
+            _ => panic!("Invalid part: {}", part),
+        }
+    }
+
+    /// A random input of about `size`, the same for the same seed, if the day
+    /// has a generator.
+    pub fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        (self.generate)(&mut Generator::new(seed, size))
+    }
+
+    /// The names of the other implementations of `part`, see
+    /// [`Solution::alternatives`].
+    pub fn alternatives(&self, part: u8) -> Vec<&'static str> {
+        (self.alternatives)(part)
+    }
+
+    /// Solves `part` with its `index`th other implementation, in the order of
+    /// [`Day::alternatives`].
+    pub fn solve_alternative(&self, part: u8, index: usize, input: &ParsedInput) -> Answer {
+        (self.solve_alternative)(part, index, input)
+    }
+}
+
+/// All the days known to the runner, sorted by day number.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u8, Day>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S>(&mut self) -> &mut Self
+    where
+        S: Solution,
+        S::Input: Send + Sync + 'static,
+    {
+        self.days.insert(S::DAY, Day::new::<S>());
+        self
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Day> {
+        self.days.get(&day)
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = &Day> {
+        self.days.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines<const DAY: u8>;
+
+    impl<const DAY: u8> Solution for Lines<DAY> {
+        const DAY: u8 = DAY;
+
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part1(input: &Self::Input) -> impl Into<Answer> {
+            input.len()
+        }
+
+        fn part2(input: &Self::Input) -> impl Into<Answer> {
+            input.concat()
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let mut registry = Registry::new();
+        registry.register::<Lines<18>>().register::<Lines<1>>();
+
+        assert!(registry.get(2).is_none());
+        assert_eq!(
+            registry.days().map(|d| d.day).collect::<Vec<_>>(),
+            vec![1, 18]
+        );
+
+        let day = registry.get(18).unwrap();
+        let input = day.parse("a\nb").unwrap();
+        assert_eq!(day.solve(1, &input), Answer::Number(2));
+        assert_eq!(day.solve(2, &input), Answer::Text("ab".to_string()));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{Alternative, Answer, Generator, Params, ParseError};
+
+/// A day's puzzle, split into a parse phase and two solve phases sharing the
+/// same parsed input.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    /// Parses the input, pointing at what is wrong with it if anything.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> impl Into<Answer>;
+
+    fn part2(input: &Self::Input) -> impl Into<Answer>;
+
+    /// Solves a part of an example from `examples.toml`. Days whose examples
+    /// need different settings than the real input read them from `params`.
+    fn example(part: u8, input: &Self::Input, params: &Params) -> Answer {
+        let _ = params;
+        match part {
+            1 => Self::part1(input).into(),
+            2 => Self::part2(input).into(),
+            _ => panic!("Invalid part: {}", part),
+        }
+    }
+
+    /// Writes a random valid input of about `gen.size()`, to find out how the
+    /// day scales before a real input does. Days without a generator return
+    /// `None`.
+    fn generate(gen: &mut Generator) -> Option<String> {
+        let _ = gen;
+        None
+    }
+
+    /// Other implementations of the parts, such as the brute force a part
+    /// replaced, which `aoc diff` checks against the registered ones.
+    fn alternatives() -> Vec<Alternative<Self::Input>> {
+        vec![]
+    }
+}
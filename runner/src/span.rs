@@ -0,0 +1,399 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    fmt::{Display, Write},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{alloc_stats_enabled, format_bytes, AllocMeter, AllocStats};
+
+thread_local! {
+    /// Spans open on this thread while a [`crate::Runner`] is running.
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+struct Recording {
+    origin: Instant,
+    meter: Option<AllocMeter>,
+    /// The open spans, the first being the runner's own
+    stack: Vec<Timing>,
+}
+
+/// Timing of a span and of the spans opened inside it. Spans with the same
+/// name under the same parent are merged, keeping each of their runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub name: Cow<'static, str>,
+    pub elapsed: Duration,
+    pub runs: Vec<Run>,
+    /// Only counted with the `alloc-stats` feature
+    pub alloc: Option<AllocStats>,
+    pub children: Vec<Timing>,
+}
+
+/// One time a span was opened, relative to the start of the runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: Duration,
+    pub elapsed: Duration,
+}
+
+impl Timing {
+    fn new(name: Cow<'static, str>) -> Self {
+        Self {
+            name,
+            elapsed: Duration::ZERO,
+            runs: vec![],
+            alloc: None,
+            children: vec![],
+        }
+    }
+
+    fn add(&mut self, child: Timing) {
+        match self.children.iter_mut().find(|c| c.name == child.name) {
+            Some(existing) => {
+                existing.elapsed += child.elapsed;
+                existing.runs.extend(child.runs);
+                if let (Some(alloc), Some(other)) = (&mut existing.alloc, child.alloc) {
+                    *alloc += other;
+                }
+                for grandchild in child.children {
+                    existing.add(grandchild);
+                }
+            }
+            None => self.children.push(child),
+        }
+    }
+
+    /// Time spent in the span itself rather than in its children.
+    pub fn self_time(&self) -> Duration {
+        let children = self.children.iter().map(|c| c.elapsed).sum();
+        self.elapsed.saturating_sub(children)
+    }
+
+    /// Every run of every span as a Chrome trace-event JSON document, to open
+    /// in `chrome://tracing`, Perfetto or speedscope.
+    pub fn chrome_trace(&self) -> String {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Trace<'a> {
+            trace_events: Vec<Event<'a>>,
+            display_time_unit: &'static str,
+        }
+
+        #[derive(Serialize)]
+        struct Event<'a> {
+            name: &'a str,
+            ph: &'static str,
+            ts: f64,
+            dur: f64,
+            pid: u32,
+            tid: u32,
+        }
+
+        fn collect<'a>(timing: &'a Timing, events: &mut Vec<Event<'a>>) {
+            events.extend(timing.runs.iter().map(|run| Event {
+                name: &timing.name,
+                ph: "X",
+                ts: run.start.as_nanos() as f64 / 1_000.0,
+                dur: run.elapsed.as_nanos() as f64 / 1_000.0,
+                pid: 1,
+                tid: 1,
+            }));
+            for child in &timing.children {
+                collect(child, events);
+            }
+        }
+
+        let mut events = vec![];
+        collect(self, &mut events);
+        // Viewers nest events by time, parents must come before their children
+        events.sort_by(|a, b| a.ts.total_cmp(&b.ts).then(b.dur.total_cmp(&a.dur)));
+
+        serde_json::to_string(&Trace {
+            trace_events: events,
+            display_time_unit: "ms",
+        })
+        .unwrap()
+    }
+
+    /// One `parent;child self-time` line per span, with the self time in
+    /// microseconds, as expected by `flamegraph.pl` and `inferno`.
+    pub fn collapsed(&self) -> String {
+        fn collect(timing: &Timing, path: &str, out: &mut String) {
+            let path = match path {
+                "" => timing.name.replace(';', ":"),
+                path => format!("{};{}", path, timing.name.replace(';', ":")),
+            };
+            let micros = timing.self_time().as_micros();
+            if micros > 0 {
+                writeln!(out, "{} {}", path, micros).unwrap();
+            }
+            for child in &timing.children {
+                collect(child, &path, out);
+            }
+        }
+
+        let mut out = String::new();
+        collect(self, "", &mut out);
+        out
+    }
+
+    fn width(&self, depth: usize) -> usize {
+        self.children
+            .iter()
+            .map(|c| c.width(depth + 1))
+            .fold(depth * 2 + self.name.len(), usize::max)
+    }
+
+    fn fmt_tree(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        depth: usize,
+        width: usize,
+        total: Duration,
+    ) -> std::fmt::Result {
+        let label = format!("{}{}", "  ".repeat(depth), self.name);
+        write!(
+            f,
+            "{:<width$}  {:>10.3}ms  {:>5.1}%",
+            label,
+            self.elapsed.as_nanos() as f64 / 1_000_000.0,
+            self.elapsed.as_secs_f64() * 100.0 / total.as_secs_f64().max(f64::MIN_POSITIVE),
+        )?;
+        if let Some(alloc) = self.alloc {
+            write!(
+                f,
+                "  {:>9} allocs  {:>9}  {:>9} peak",
+                alloc.allocations,
+                format_bytes(alloc.bytes),
+                format_bytes(alloc.peak)
+            )?;
+        }
+        if self.runs.len() > 1 {
+            write!(f, "  x{}", self.runs.len())?;
+        }
+        writeln!(f)?;
+
+        for child in &self.children {
+            child.fmt_tree(f, depth + 1, width, total)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints as an indented breakdown, with each span's share of the total.
+impl Display for Timing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_tree(f, 0, self.width(0), self.elapsed)
+    }
+}
+
+/// Times the code until it is dropped, as a child of the innermost open span.
+/// Does nothing unless a [`crate::Runner`] is running on the same thread, so
+/// spans can stay in the solutions when benchmarking or testing.
+#[must_use = "the span closes as soon as it is dropped"]
+pub struct Span {
+    /// When the span started, and how many spans were open with it
+    open: Option<(Instant, usize)>,
+    meter: Option<AllocMeter>,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some((start, depth)) = self.open else {
+            return;
+        };
+        let elapsed = start.elapsed();
+        let alloc = self.meter.take().map(AllocMeter::stop);
+
+        RECORDING.with_borrow_mut(|recording| {
+            // The runner ended, or started over, while the span was open
+            let Some(recording) = recording.as_mut().filter(|r| r.stack.len() == depth) else {
+                return;
+            };
+            let mut timing = recording.stack.pop().unwrap();
+            timing.elapsed += elapsed;
+            timing.alloc = alloc;
+            timing.runs.push(Run {
+                start: start.saturating_duration_since(recording.origin),
+                elapsed,
+            });
+            if let Some(parent) = recording.stack.last_mut() {
+                parent.add(timing);
+            }
+        });
+    }
+}
+
+/// Opens a span named `name`, see [`Span`].
+pub fn span(name: impl Into<Cow<'static, str>>) -> Span {
+    RECORDING.with_borrow_mut(|recording| {
+        let Some(recording) = recording else {
+            return Span {
+                open: None,
+                meter: None,
+            };
+        };
+        recording.stack.push(Timing::new(name.into()));
+        Span {
+            open: Some((Instant::now(), recording.stack.len())),
+            meter: alloc_stats_enabled().then(AllocMeter::start),
+        }
+    })
+}
+
+/// Starts recording spans on this thread under a new root.
+pub(crate) fn begin(name: &'static str, origin: Instant) {
+    RECORDING.set(Some(Recording {
+        origin,
+        meter: alloc_stats_enabled().then(AllocMeter::start),
+        stack: vec![Timing::new(name.into())],
+    }));
+}
+
+/// When the recording on this thread started, if any.
+pub(crate) fn origin() -> Option<Instant> {
+    RECORDING.with_borrow(|recording| recording.as_ref().map(|r| r.origin))
+}
+
+/// Adds spans recorded on another thread under the innermost open span.
+pub(crate) fn graft(spans: Vec<Timing>) {
+    RECORDING.with_borrow_mut(|recording| {
+        let Some(parent) = recording.as_mut().and_then(|r| r.stack.last_mut()) else {
+            return;
+        };
+        for span in spans {
+            parent.add(span);
+        }
+    });
+}
+
+/// Stops recording, returning the root with every span closed so far.
+pub(crate) fn finish(elapsed: Duration) -> Option<Timing> {
+    let recording = RECORDING.take()?;
+    let mut root = recording.stack.into_iter().next()?;
+    root.elapsed = elapsed;
+    root.alloc = recording.meter.map(AllocMeter::stop);
+    root.runs = vec![Run {
+        start: Duration::ZERO,
+        elapsed,
+    }];
+    Some(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(name: &'static str, start: u64, elapsed: u64, children: Vec<Timing>) -> Timing {
+        Timing {
+            name: name.into(),
+            elapsed: Duration::from_micros(elapsed),
+            runs: vec![Run {
+                start: Duration::from_micros(start),
+                elapsed: Duration::from_micros(elapsed),
+            }],
+            alloc: None,
+            children,
+        }
+    }
+
+    #[test]
+    fn test_tree() {
+        begin("run", Instant::now());
+        {
+            let _parse = span("parse");
+            for _ in 0..3 {
+                let _bfs = span("bfs");
+                let _step = span("step");
+            }
+            let _sum = span(format!("sum {}", 1));
+        }
+        let root = finish(Duration::from_millis(10)).unwrap();
+
+        assert_eq!(root.name, "run");
+        assert_eq!(root.children.len(), 1);
+        let parse = &root.children[0];
+        assert_eq!(parse.name, "parse");
+        assert_eq!(parse.runs.len(), 1);
+        let names = parse.children.iter().map(|c| &c.name).collect::<Vec<_>>();
+        assert_eq!(names, ["bfs", "sum 1"]);
+        assert_eq!(parse.children[0].runs.len(), 3);
+        assert_eq!(parse.children[0].children[0].runs.len(), 3);
+        assert!(parse.elapsed >= parse.children[0].elapsed);
+        assert!(parse.children[0].runs[0].start <= parse.children[0].runs[1].start);
+    }
+
+    #[test]
+    fn test_not_recording() {
+        let span = span("parse");
+        assert!(span.open.is_none());
+        drop(span);
+        assert!(finish(Duration::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_finish_with_open_span() {
+        begin("run", Instant::now());
+        let open = span("parse");
+        let root = finish(Duration::ZERO).unwrap();
+        drop(open);
+        assert!(root.children.is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        let mut root = Timing::new("run".into());
+        root.elapsed = Duration::from_millis(10);
+        let mut bfs = timing("bfs", 0, 2500, vec![]);
+        bfs.runs = vec![bfs.runs[0]; 4];
+        root.add(bfs);
+
+        assert_eq!(
+            root.to_string(),
+            "run        10.000ms  100.0%\n  \
+             bfs       2.500ms   25.0%  x4\n"
+        );
+
+        root.alloc = Some(AllocStats {
+            allocations: 12,
+            bytes: 2048,
+            peak: 100,
+        });
+        assert_eq!(
+            root.to_string().lines().next().unwrap(),
+            "run        10.000ms  100.0%         12 allocs     2.0KiB       100B peak"
+        );
+    }
+
+    #[test]
+    fn test_collapsed() {
+        let root = timing(
+            "total",
+            0,
+            100,
+            vec![timing(
+                "part 1",
+                10,
+                80,
+                vec![timing("a;b", 20, 30, vec![])],
+            )],
+        );
+        assert_eq!(
+            root.collapsed(),
+            "total 20\ntotal;part 1 50\ntotal;part 1;a:b 30\n"
+        );
+    }
+
+    #[test]
+    fn test_chrome_trace() {
+        let root = timing("total", 0, 100, vec![timing("part \"1\"", 0, 80, vec![])]);
+        assert_eq!(
+            root.chrome_trace(),
+            r#"{"traceEvents":[{"name":"total","ph":"X","ts":0.0,"dur":100.0,"pid":1,"tid":1},{"name":"part \"1\"","ph":"X","ts":0.0,"dur":80.0,"pid":1,"tid":1}],"displayTimeUnit":"ms"}"#
+        );
+    }
+}
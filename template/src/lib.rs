@@ -1,2 +1,26 @@
+use runner::{Answer, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct {{name}};
+
+impl Solution for {{name}} {
+    const DAY: u8 = {{day}};
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> impl Into<Answer> {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Into<Answer> {
+        part2::part2(input)
+    }
+}
+
+runner::example_tests!({{name}});
@@ -1,8 +0,0 @@
-use runner::Runner;
-
-fn main() {
-    let runner = Runner::start();
-    let input = include_str!("../input.txt");
-    println!("Hello, advent of code!");
-    runner.end();
-}
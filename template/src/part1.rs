@@ -1,12 +1,2 @@
-pub fn part1() {}
-
-#[cfg(test)]
-pub mod tests {
-    use crate::part1::*;
-
-    #[test]
-    fn test_example() {
-        let input = include_str!("../test.txt");
-        assert_eq!(1, 2);
-    }
-}
+/// Unsolved until it returns an answer
+pub fn part1(_input: &str) {}
@@ -20,10 +20,8 @@ impl FromStr for Key {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.len() > 4 {
-            debug!(
-                "[WARN] Key too long: '{}', using only first 4 characters!",
-                s
-            );
+            // Debug only: day 20 truncates its module names on every press
+            debug!("Key too long: '{}', using only first 4 characters!", s);
         }
 
         Ok(Self(
@@ -1,6 +1,7 @@
 pub mod color;
-pub mod debug;
 pub mod graph;
 pub mod iter;
 pub mod key;
+pub mod log;
 pub mod map;
+pub mod parse;
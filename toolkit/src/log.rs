@@ -0,0 +1,244 @@
+//! Leveled logging on stderr, filtered per crate or module at runtime.
+//!
+//! The filter comes from `AOC_LOG` (or `aoc --log`), as a comma separated list
+//! of `target=level` directives and an optional default level:
+//!
+//! ```sh
+//! AOC_LOG=day_12=trace,day_07::part2=debug,info
+//! ```
+//!
+//! A target matches its module and the modules below it, and the longest
+//! matching target wins. Without a filter only warnings and errors are shown.
+//! A disabled call costs an atomic load and does not evaluate its arguments.
+
+use std::{
+    env,
+    fmt::{self, Display},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+pub const ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("Unknown log level: '{}'", s)),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// Which levels are shown for which modules. Levels are stored as `u8`, with
+/// 0 meaning off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: u8,
+    /// Sorted by decreasing target length, so the first match is the best
+    directives: Vec<(String, u8)>,
+}
+
+impl Filter {
+    const fn new(default: Level) -> Self {
+        Self {
+            default: default as u8,
+            directives: Vec::new(),
+        }
+    }
+
+    fn level_for(&self, target: &str) -> u8 {
+        self.directives
+            .iter()
+            .find(|(prefix, _)| {
+                target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    fn max_level(&self) -> u8 {
+        self.directives
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.default, u8::max)
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self::new(Level::Warn)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_level = |level: &str| match level {
+            "off" => Ok(0),
+            level => level.parse::<Level>().map(|l| l as u8),
+        };
+
+        let mut filter = Filter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                // Crate names are written as in Cargo.toml, or as in paths
+                Some((target, level)) => filter
+                    .directives
+                    .push((target.replace('-', "_"), parse_level(level)?)),
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+        filter
+            .directives
+            .sort_by_key(|(target, _)| std::cmp::Reverse(target.len()));
+        Ok(filter)
+    }
+}
+
+/// Highest level enabled for any target, or `UNSET` before the filter is read.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(UNSET);
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new(Level::Warn));
+const UNSET: u8 = u8::MAX;
+
+/// Replaces the filter read from `AOC_LOG`.
+pub fn set_filter(filter: Filter) {
+    let max = filter.max_level();
+    *FILTER.write().unwrap() = filter;
+    MAX_LEVEL.store(max, Ordering::Relaxed);
+}
+
+#[cold]
+fn init_from_env() -> u8 {
+    let filter = match env::var(ENV_VAR) {
+        Ok(spec) => spec.parse().unwrap_or_else(|err| {
+            eprintln!("Ignoring {}: {}", ENV_VAR, err);
+            Filter::default()
+        }),
+        Err(_) => Filter::default(),
+    };
+    // Another thread may have set a filter in the meantime
+    if MAX_LEVEL.load(Ordering::Relaxed) == UNSET {
+        set_filter(filter);
+    }
+    MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Whether a message at `level` from the `target` module would be shown.
+#[inline]
+pub fn enabled(level: Level, target: &str) -> bool {
+    let max = match MAX_LEVEL.load(Ordering::Relaxed) {
+        UNSET => init_from_env(),
+        max => max,
+    };
+    level as u8 <= max && level as u8 <= FILTER.read().unwrap().level_for(target)
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    eprintln!("{:<5} {}: {}", level, target, args);
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Whether this module logs at `level`, to skip work that only feeds a log,
+/// such as printing a whole map.
+#[macro_export]
+macro_rules! log_enabled {
+    ($level:expr) => {
+        $crate::log::enabled($level, module_path!())
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+/// Used to print in debug builds only, now logs at the debug level in every
+/// build, so existing calls keep working and can be turned on in release.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter: Filter = "day-12=trace, day_07::part2=debug,day_07=off,info"
+            .parse()
+            .unwrap();
+
+        assert_eq!(filter.level_for("day_12::part2"), Level::Trace as u8);
+        assert_eq!(filter.level_for("day_07::part2"), Level::Debug as u8);
+        assert_eq!(filter.level_for("day_07::part1"), 0);
+        // Only whole path segments match
+        assert_eq!(filter.level_for("day_123"), Level::Info as u8);
+        assert_eq!(filter.max_level(), Level::Trace as u8);
+    }
+
+    #[test]
+    fn test_default() {
+        let filter = Filter::default();
+        assert_eq!(filter.level_for("day_01"), Level::Warn as u8);
+        assert_eq!("".parse::<Filter>(), Ok(filter));
+        assert!("day_01=loud".parse::<Filter>().is_err());
+    }
+}
@@ -1,12 +1,16 @@
 pub mod bounds;
+pub mod ocr;
 pub mod pos;
 
 pub use bounds::*;
 use colored::Colorize;
+pub use ocr::*;
 pub use pos::*;
 
 use std::{collections::HashMap, fmt::Display, ops::Index};
 
+use crate::parse::ParseError;
+
 pub type Neighbor<'a, T> = (Pos, &'a T);
 
 /// A trait for types that can be displayed as a tile on a map.
@@ -61,6 +65,34 @@ impl<T> Map<T> {
         map
     }
 
+    /// Like [`Map::parse`], for tiles that can be invalid: `parse_tile` then
+    /// returns what it expected instead, and the error points at the tile.
+    pub fn try_parse(
+        input: &str,
+        mut parse_tile: impl FnMut(char, usize, usize) -> Result<Option<T>, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let mut map = Self::new();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                match parse_tile(c, x, y) {
+                    Ok(Some(tile)) => {
+                        map.tiles.insert(Pos::new(x, y), tile);
+                    }
+                    Ok(None) => {}
+                    Err(expected) => {
+                        let tile = &line[i..i + c.len_utf8()];
+                        return Err(ParseError::at(input, tile, expected));
+                    }
+                }
+            }
+        }
+
+        map.update_bounds();
+
+        Ok(map)
+    }
+
     pub fn width(&self) -> i32 {
         self.bounds.width()
     }
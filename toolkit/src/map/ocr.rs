@@ -0,0 +1,265 @@
+//! Reads the block letters some puzzles draw as their answer, in the two fonts
+//! they use: 4 pixels wide and 6 high, or 6 wide and 10 high.
+//!
+//! ```ignore
+//! let code = map.read_letters(|tile| *tile == Tile::Lit)?;
+//! ```
+
+use std::{error::Error, fmt};
+
+use super::{Map, Pos};
+
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+// Too wide for one glyph per line otherwise
+#[rustfmt::skip]
+const FONT_10: [(char, &str); 15] = [
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// No pixel is lit
+    Empty,
+    /// The letters are neither 6 nor 10 pixels high
+    Height(usize),
+    /// A glyph is not a letter of the font
+    Glyph {
+        /// 0-based, from the left
+        index: usize,
+        /// Where the glyph starts and how wide it is, in pixels
+        column: usize,
+        width: usize,
+        /// The rows holding the letters, drawn with `#` and `.`
+        rows: Vec<String>,
+    },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Empty => write!(f, "no letters, nothing is lit"),
+            OcrError::Height(height) => {
+                write!(f, "letters are {} pixels high, expected 6 or 10", height)
+            }
+            OcrError::Glyph {
+                index,
+                column,
+                width,
+                rows,
+            } => {
+                writeln!(f, "unknown letter {} at column {}", index + 1, column + 1)?;
+                for row in rows {
+                    writeln!(f, "{}", row)?;
+                }
+                write!(f, "{}{}", " ".repeat(*column), "^".repeat(*width))
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// Reads the letters drawn by the lit `pixels`, which may have blank rows and
+/// columns around them. Letters are told apart by the blank columns between
+/// them.
+pub fn read_letters(pixels: &[Vec<bool>]) -> Result<String, OcrError> {
+    let mut lit_rows = pixels
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| row.contains(&true))
+        .map(|(y, _)| y);
+    let (top, bottom) = match (lit_rows.next(), lit_rows.next_back()) {
+        (Some(top), bottom) => (top, bottom.unwrap_or(top)),
+        _ => return Err(OcrError::Empty),
+    };
+    let font = match bottom - top + 1 {
+        6 => &FONT_6[..],
+        10 => &FONT_10[..],
+        height => return Err(OcrError::Height(height)),
+    };
+
+    let rows = &pixels[top..=bottom];
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let blank = |x: usize| (0..rows.len()).all(|y| !lit(x, y));
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && !blank(x) {
+            x += 1;
+        }
+
+        let glyph = (0..rows.len())
+            .map(|y| (start..x).map(|x| draw(lit(x, y))).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        match font.iter().find(|(_, g)| trim_columns(g) == glyph) {
+            Some(&(letter, _)) => letters.push(letter),
+            None => {
+                return Err(OcrError::Glyph {
+                    index: letters.chars().count(),
+                    column: start,
+                    width: x - start,
+                    rows: (0..rows.len())
+                        .map(|y| (0..width).map(|x| draw(lit(x, y))).collect())
+                        .collect(),
+                })
+            }
+        }
+    }
+    Ok(letters)
+}
+
+fn draw(lit: bool) -> char {
+    if lit {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+/// Drops the blank columns on the sides of a font glyph, as they cannot be
+/// told apart from the spacing between letters.
+fn trim_columns(glyph: &str) -> String {
+    let rows = glyph.lines().collect::<Vec<_>>();
+    let lit = |x: usize| rows.iter().any(|row| row.as_bytes()[x] == b'#');
+    let width = rows[0].len();
+    let start = (0..width).find(|&x| lit(x)).unwrap_or(0);
+    let end = (0..width).rfind(|&x| lit(x)).map_or(0, |x| x + 1);
+    rows.iter()
+        .map(|row| &row[start..end])
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl<T> Map<T> {
+    /// The map as rows of pixels, lit where `lit` says so. Missing tiles are
+    /// not lit.
+    pub fn pixels(&self, lit: impl Fn(&T) -> bool) -> Vec<Vec<bool>> {
+        (self.bounds.min.y..=self.bounds.max.y)
+            .map(|y| {
+                (self.bounds.min.x..=self.bounds.max.x)
+                    .map(|x| self.get(Pos::new(x, y)).is_some_and(&lit))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Reads the letters drawn by the tiles for which `lit` is true, see
+    /// [`read_letters`].
+    pub fn read_letters(&self, lit: impl Fn(&T) -> bool) -> Result<String, OcrError> {
+        read_letters(&self.pixels(lit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixels(drawing: &str) -> Vec<Vec<bool>> {
+        drawing
+            .lines()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_font_6() {
+        let drawing = "\
+            ......................\n\
+            .#..#.####.#...#.###..\n\
+            .#..#.#....#...#.#..#.\n\
+            .####.###...#.#..#..#.\n\
+            .#..#.#......#...###..\n\
+            .#..#.#......#...#....\n\
+            .#..#.####...#...#....\n";
+        assert_eq!(read_letters(&pixels(drawing)), Ok("HEYP".to_string()));
+    }
+
+    #[test]
+    fn test_font_10() {
+        let drawing = [
+            "#....#..######",
+            "#....#.......#",
+            ".#..#........#",
+            ".#..#.......#.",
+            "..##.......#..",
+            "..##......#...",
+            ".#..#....#....",
+            ".#..#...#.....",
+            "#....#..#.....",
+            "#....#..######",
+        ]
+        .join("\n");
+        assert_eq!(read_letters(&pixels(&drawing)), Ok("XZ".to_string()));
+    }
+
+    #[test]
+    fn test_map() {
+        let drawing = ".##..####\n#..#.#...\n#..#.###.\n####.#...\n#..#.#...\n#..#.#...";
+        let map = Map::parse(drawing, |c, _, _| Some(c == '#'));
+        assert_eq!(map.read_letters(|&lit| lit), Ok("AF".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let drawing = "#..#.#.#\n#..#.#.#\n####.###\n#..#...#\n#..#...#\n#..#...#";
+        let err = read_letters(&pixels(drawing)).unwrap_err();
+
+        assert!(matches!(
+            err,
+            OcrError::Glyph {
+                index: 1,
+                column: 5,
+                width: 3,
+                ..
+            }
+        ));
+        assert!(err
+            .to_string()
+            .starts_with("unknown letter 2 at column 6\n"));
+        assert!(err.to_string().ends_with("#..#...#\n     ^^^"));
+        assert_eq!(read_letters(&pixels("#\n#")), Err(OcrError::Height(2)));
+        assert_eq!(read_letters(&pixels("...")), Err(OcrError::Empty));
+    }
+}
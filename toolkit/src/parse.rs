@@ -0,0 +1,198 @@
+//! Parse errors that point at the offending part of the input.
+//!
+//! Parsers usually work on slices of the input (lines, words...), so errors
+//! are built from the slice that is wrong and located from where it sits in
+//! the whole input:
+//!
+//! ```ignore
+//! let count = parse_at(input, word, "a number")?;
+//! ```
+//!
+//! which displays as
+//!
+//! ```text
+//! line 3, column 9: expected a number, found "x"
+//!   |
+//! 3 | Game 3: x blue
+//!   |         ^
+//! ```
+
+use std::{error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// In bytes, from the start of the input
+    pub offset: usize,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+    /// The whole line the error is on
+    pub snippet: String,
+    /// What was found instead, empty at the end of a line
+    pub found: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error about `found`, which must be a slice of `input`. Empty slices
+    /// point between two characters, e.g. at a missing end of line.
+    pub fn at(input: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = (found.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + found.len() <= input.len())
+            .expect("found is not a slice of the input");
+        Self::at_offset(input, offset, found.len(), expected)
+    }
+
+    /// Locates an error about `part` in the whole `input` it is a slice of,
+    /// for parsers that only get to see a part of the input.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let start = Self::at(input, part, "").offset;
+        Self::at_offset(input, start + self.offset, self.found.len(), self.expected)
+    }
+
+    /// An error about the `len` bytes of `input` from `offset`.
+    pub fn at_offset(input: &str, offset: usize, len: usize, expected: impl Into<String>) -> Self {
+        let start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let found = &input[offset..(offset + len).min(end)];
+
+        Self {
+            offset,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[start..offset].chars().count() + 1,
+            snippet: input[start..end].to_string(),
+            found: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+}
+
+/// Writes `s` with its control characters escaped, so that a stray `\r` shows
+/// up, and returns how many columns each character took.
+fn escape(s: &str, out: &mut String) -> Vec<usize> {
+    s.chars()
+        .map(|c| {
+            let before = out.len();
+            match c.is_control() {
+                true => out.extend(c.escape_debug()),
+                false => out.push(c),
+            }
+            out[before..].chars().count()
+        })
+        .collect()
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let found = match self.found.is_empty() {
+            true => "the end of the line".to_string(),
+            false => format!("{:?}", self.found),
+        };
+        writeln!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, found
+        )?;
+
+        let mut snippet = String::new();
+        let widths = escape(&self.snippet, &mut snippet);
+        let indent = widths[..self.column - 1].iter().sum::<usize>();
+        let underline = widths
+            .iter()
+            .skip(self.column - 1)
+            .take(self.found.chars().count())
+            .sum::<usize>()
+            .max(1);
+
+        let margin = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} |", margin)?;
+        writeln!(f, "{} | {}", self.line, snippet)?;
+        write!(
+            f,
+            "{} | {}{}",
+            margin,
+            " ".repeat(indent),
+            "^".repeat(underline)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `s`, a slice of `input`, or tells where it is and what it should
+/// have been.
+pub fn parse_at<T: FromStr>(input: &str, s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::at(input, s, expected))
+}
+
+/// Strips `prefix` from `s`, a slice of `input`, or points at what is there
+/// instead.
+pub fn expect_prefix<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        let found = s.get(..prefix.len()).unwrap_or(s);
+        ParseError::at(input, found, format!("{:?}", prefix))
+    })
+}
+
+/// Splits `s`, a slice of `input`, around the first `delimiter`, or points at
+/// its end if there is none.
+pub fn split_once_at<'a>(
+    input: &str,
+    s: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, &s[s.len()..], format!("{:?}", delimiter)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let input = "Game 1: 3 blue\nGame 2: x blue\n";
+        let word = &input[23..24];
+        let err = parse_at::<u32>(input, word, "a number").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 9));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 9: expected a number, found \"x\"\n  |\n2 | Game 2: x blue\n  |         ^"
+        );
+    }
+
+    #[test]
+    fn test_carriage_return() {
+        let input = "12\r\n34";
+        let line = input.split('\n').next().unwrap();
+        let err = parse_at::<u32>(input, line, "a number").unwrap_err();
+
+        assert_eq!(err.snippet, "12\r");
+        assert!(err.to_string().ends_with("1 | 12\\r\n  | ^^^^"));
+    }
+
+    #[test]
+    fn test_end_of_line() {
+        let input = "seeds 1 2";
+        let err = split_once_at(input, input, ": ").unwrap_err();
+
+        assert_eq!((err.line, err.column), (1, 10));
+        assert!(err.to_string().contains("found the end of the line"));
+        assert!(expect_prefix(input, input, "seeds: ").is_err());
+    }
+
+    #[test]
+    fn test_within() {
+        let input = "#.#\n\n##\n.x";
+        let part = &input[5..];
+        let err = ParseError::at(part, &part[4..5], "a tile").within(input, part);
+
+        assert_eq!((err.line, err.column), (4, 2));
+        assert_eq!(err.snippet, ".x");
+    }
+}